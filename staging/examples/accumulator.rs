@@ -0,0 +1,94 @@
+//! `Accumulator` collects errors in hand-written code, here a `FromStr` impl that fills in
+//! a checker from `key=value` pairs and rejects unknown or repeated keys.
+
+use std::{fmt, str::FromStr};
+
+use staging::{Accumulator, Errors, Staging};
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    InvalidNumber(String),
+    UnknownKey(String),
+    RepeatedKey(String),
+    NoValue(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(field) => write!(f, "`{}` is required", field),
+            Error::InvalidNumber(value) => write!(f, "`{}` is not a number", value),
+            Error::UnknownKey(key) => write!(f, "unknown key `{}`", key),
+            Error::RepeatedKey(key) => write!(f, "`{}` is given more than once", key),
+            Error::NoValue(pair) => write!(f, "`{}` has no value", pair),
+        }
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(error = Error, final_error = Errors::<Error>, builder = Error::Missing)]
+struct Window {
+    title: String,
+    width: u32,
+    height: u32,
+}
+
+fn number(value: &str) -> Result<u32, Error> {
+    value
+        .parse()
+        .map_err(|_| Error::InvalidNumber(value.to_string()))
+}
+
+/// Fill in a checker from `key=value` pairs. Problems with the input itself are returned
+/// all at once; problems with the values are left in the checker.
+impl FromStr for WindowStaging {
+    type Err = Errors<Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut errors = Accumulator::new();
+        let mut staging = WindowStaging::new();
+        let mut seen = vec![];
+        for pair in s.split_whitespace() {
+            let Some((key, value)) = pair.split_once('=') else {
+                errors.push(Error::NoValue(pair.to_string()));
+                continue;
+            };
+
+            if seen.contains(&key) {
+                errors.push(Error::RepeatedKey(key.to_string()));
+            }
+            seen.push(key);
+
+            match key {
+                "title" => staging.title = Ok(value.to_string()),
+                "width" => staging.width = number(value),
+                "height" => staging.height = number(value),
+                _ => errors.push(Error::UnknownKey(key.to_string())),
+            }
+        }
+
+        errors.finish_with(|| staging).map_err(Errors::from)
+    }
+}
+
+fn main() {
+    for line in [
+        "title=Editor width=800 height=600",
+        "title=Editor width=wide",
+        "title=Editor depth=3 width=800 width=900 height",
+    ] {
+        let staging = match line.parse::<WindowStaging>() {
+            Ok(staging) => staging,
+            Err(errors) => {
+                println!("Could not read `{}`:\n{}", line, errors);
+                continue;
+            }
+        };
+
+        match Window::try_from(staging) {
+            Ok(window) => println!("{}: {}x{}", window.title, window.width, window.height),
+            Err(errors) => println!("Invalid window `{}`:\n{}", line, errors),
+        }
+    }
+}
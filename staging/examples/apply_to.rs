@@ -0,0 +1,39 @@
+//! `apply_to` writes the fields that hold a value onto an existing target and leaves the
+//! rest unchanged, for partial updates.
+
+use staging::Staging;
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, apply_to)]
+struct Profile {
+    name: String,
+    bio: String,
+    #[staging(optional)]
+    website: Option<String>,
+}
+
+fn main() {
+    let mut profile = Profile {
+        name: "Ferris".to_string(),
+        bio: "Likes the sea".to_string(),
+        website: Some("https://rust-lang.org".to_string()),
+    };
+
+    // The new bio is invalid and the website was not sent, so only the name changes.
+    ProfileStaging {
+        name: Ok("Ferris the Crab".to_string()),
+        bio: Err("bio is too long".to_string()),
+        website: None,
+    }
+    .apply_to(&mut profile);
+
+    assert_eq!(
+        profile,
+        Profile {
+            name: "Ferris the Crab".to_string(),
+            bio: "Likes the sea".to_string(),
+            website: Some("https://rust-lang.org".to_string()),
+        }
+    );
+    println!("Applied the valid fields of the update");
+}
@@ -0,0 +1,57 @@
+//! `as_ref` borrows every field of the checker at once, so a check that involves several
+//! fields can look at the staged values without cloning or consuming the checker.
+
+use staging::Staging;
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, as_ref)]
+struct Booking {
+    nights: u32,
+    guests: u32,
+    #[staging(optional)]
+    note: Option<String>,
+}
+
+/// Reject bookings that are too large, when both numbers are known.
+fn check_size(booking: &BookingStaging) -> Result<(), String> {
+    let view = booking.as_ref();
+    match (view.nights, view.guests) {
+        (Ok(nights), Ok(guests)) if nights * guests > 20 => Err(format!(
+            "{} guests for {} nights is too many guest nights",
+            guests, nights
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn main() {
+    let large = BookingStaging {
+        nights: Ok(7),
+        guests: Ok(4),
+        note: None,
+    };
+    assert_eq!(
+        check_size(&large),
+        Err("4 guests for 7 nights is too many guest nights".to_string())
+    );
+    assert_eq!(large.as_ref().note, None);
+
+    let small = BookingStaging {
+        nights: Ok(2),
+        guests: Err("guests must be a number".to_string()),
+        note: Some(Ok("late arrival".to_string())),
+    };
+    assert_eq!(check_size(&small), Ok(()));
+    assert_eq!(small.as_ref().note, Some(Ok(&"late arrival".to_string())));
+
+    // The checkers can still be finalized afterwards.
+    assert_eq!(
+        Booking::try_from(large).unwrap(),
+        Booking {
+            nights: 7,
+            guests: 4,
+            note: None,
+        }
+    );
+    println!("Checked the booking size through borrowed views");
+}
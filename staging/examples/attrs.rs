@@ -0,0 +1,39 @@
+//! `attrs(...)` puts any attributes on the checker, or on one of its fields, without
+//! staging needing to know what they are for.
+
+use serde::Serialize;
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(
+    error = String,
+    final_error = Errors::<String>,
+    attrs(#[derive(Clone, Serialize)], #[must_use])
+)]
+struct Login {
+    user: String,
+    #[staging(attrs(#[serde(skip)]))]
+    password: String,
+}
+
+fn main() {
+    let checker = LoginStaging {
+        user: Ok("ferris".to_string()),
+        password: Ok("hunter2".to_string()),
+    };
+    assert_eq!(
+        serde_json::to_string(&checker).unwrap(),
+        r#"{"user":{"Ok":"ferris"}}"#
+    );
+
+    let login = Login::try_from(checker.clone()).unwrap();
+    assert_eq!(
+        login,
+        Login {
+            user: "ferris".to_string(),
+            password: "hunter2".to_string(),
+        }
+    );
+    assert_eq!(Login::try_from(checker).unwrap(), login);
+    println!("Derived traits and skipped the password through forwarded attributes");
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+
+use staging::{AtLeastOne, Staging, stage};
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    InvalidEmail(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(field) => write!(f, "`{}` is required", field),
+            Error::InvalidEmail(email) => write!(f, "`{}` is not an email address", email),
+        }
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(
+    error = Error,
+    final_error = AtLeastOne::<Error>,
+    non_empty,
+    builder = Error::Missing
+)]
+struct User {
+    #[staging(into)]
+    name: String,
+    #[staging(into)]
+    email: String,
+    #[staging(optional, into)]
+    nickname: Option<String>,
+    #[staging(infallible)]
+    admin: bool,
+}
+
+impl fmt::Display for User {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} <{}>", self.name, self.email)?;
+        if let Some(nickname) = &self.nickname {
+            write!(f, " ({})", nickname)?;
+        }
+        if self.admin {
+            write!(f, " [admin]")?;
+        }
+        Ok(())
+    }
+}
+
+fn email(value: &str) -> Result<String, Error> {
+    if value.contains('@') {
+        Ok(value.to_string())
+    } else {
+        Err(Error::InvalidEmail(value.to_string()))
+    }
+}
+
+fn main() {
+    let mut builder = UserStaging::new();
+    builder
+        .set_name("Alice")
+        .try_set_email(email("alice@example.com"))
+        .set_nickname("al")
+        .set_admin(true);
+
+    match builder.build() {
+        Ok(user) => println!("Built {}", user),
+        Err(errors) => println!("Failed to build user:\n{}", errors),
+    }
+
+    let builder = stage! { UserStaging { email: email("bob") } };
+
+    match builder.build() {
+        Ok(user) => println!("Built {}", user),
+        Err(errors) => println!(
+            "Failed to build user ({} errors, first: {}):\n{}",
+            errors.len(),
+            errors.first(),
+            errors
+        ),
+    }
+
+    let name = Ok("Carol".to_string());
+    let builder = stage! {
+        UserStaging {
+            name,
+            email: email("carol@example.com"),
+            admin: false,
+        }
+    };
+    match builder.build() {
+        Ok(user) => println!("Built {}", user),
+        Err(errors) => println!("Failed to build user:\n{}", errors),
+    }
+}
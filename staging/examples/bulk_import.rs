@@ -0,0 +1,63 @@
+//! `try_collect_all` finalizes a whole batch of checkers, here one per line of an import,
+//! and reports every line that failed rather than stopping at the first.
+
+use std::fmt;
+
+use staging::{Errors, Staging, try_collect_all};
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    InvalidPrice(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(field) => write!(f, "`{}` is required", field),
+            Error::InvalidPrice(value) => write!(f, "`{}` is not a price", value),
+        }
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(error = Error, final_error = Errors::<Error>, builder = Error::Missing)]
+struct Product {
+    sku: String,
+    price_cents: u64,
+}
+
+/// Stage a `sku,price` line, leaving missing columns as missing fields.
+fn stage(line: &str) -> ProductStaging {
+    let mut staging = ProductStaging::new();
+    let mut columns = line.split(',').map(str::trim).filter(|c| !c.is_empty());
+    if let Some(sku) = columns.next() {
+        staging.set_sku(sku.to_string());
+    }
+    if let Some(price) = columns.next() {
+        staging.price_cents = price
+            .parse()
+            .map_err(|_| Error::InvalidPrice(price.to_string()));
+    }
+    staging
+}
+
+fn import(batch: &[&str]) {
+    match try_collect_all::<_, Product, _>(batch.iter().map(|line| stage(line))) {
+        Ok(products) => {
+            for product in products {
+                println!("Imported {} at {} cents", product.sku, product.price_cents);
+            }
+        }
+        Err(failures) => {
+            for (index, errors) in failures {
+                println!("Line {} was rejected:\n{}", index + 1, errors);
+            }
+        }
+    }
+}
+
+fn main() {
+    import(&["A-100, 250", "B-200, 1999"]);
+    import(&["A-100, 250", "B-200, cheap", ",", "C-300, 75"]);
+}
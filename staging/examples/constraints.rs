@@ -0,0 +1,136 @@
+use std::fmt;
+
+use staging::Staging;
+
+#[derive(Debug)]
+enum Error {
+    InvalidPort,
+    NoListener,
+    CertRequiresKey,
+    SocketConflictsWithTls,
+    Multiple(Vec<Error>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidPort => write!(f, "invalid port"),
+            Error::NoListener => write!(f, "one of `--port` or `--unix-socket` is required"),
+            Error::CertRequiresKey => write!(f, "`--tls-cert` requires `--tls-key`"),
+            Error::SocketConflictsWithTls => {
+                write!(f, "`--unix-socket` cannot be used with `--tls-cert`")
+            }
+            Error::Multiple(errors) => {
+                for (i, err) in errors.iter().enumerate() {
+                    writeln!(f, "{}: {}", i + 1, err)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromIterator<Error> for Error {
+    fn from_iter<T: IntoIterator<Item = Error>>(iter: T) -> Self {
+        let mut errors: Vec<Error> = iter.into_iter().collect();
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            Error::Multiple(errors)
+        }
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(
+    error = Error,
+    at_least_one_of(port, unix_socket, error = Error::NoListener),
+    setters
+)]
+struct ServerArgs {
+    #[staging(optional)]
+    port: Option<u16>,
+    #[staging(
+        optional,
+        into,
+        requires(field = tls_key, error = Error::CertRequiresKey)
+    )]
+    tls_cert: Option<String>,
+    #[staging(optional, into)]
+    tls_key: Option<String>,
+    #[staging(
+        optional,
+        into,
+        conflicts_with(field = tls_cert, error = Error::SocketConflictsWithTls)
+    )]
+    unix_socket: Option<String>,
+}
+
+impl fmt::Display for ServerArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(port) = self.port {
+            write!(f, "port {}", port)?;
+        }
+        if let Some(path) = &self.unix_socket {
+            write!(f, "socket {}", path)?;
+        }
+        if let (Some(cert), Some(key)) = (&self.tls_cert, &self.tls_key) {
+            write!(f, " with TLS ({}, {})", cert, key)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse(args: &[(&str, &str)]) -> Result<ServerArgs, Error> {
+    let mut staging = ServerArgsStaging {
+        port: None,
+        tls_cert: None,
+        tls_key: None,
+        unix_socket: None,
+    };
+
+    for (name, value) in args {
+        match *name {
+            "--port" => staging.port = Some(value.parse().map_err(|_| Error::InvalidPort)),
+            "--tls-cert" => {
+                staging.set_tls_cert(*value);
+            }
+            "--tls-key" => {
+                staging.set_tls_key(*value);
+            }
+            "--unix-socket" => {
+                staging.set_unix_socket(*value);
+            }
+            _ => {}
+        }
+    }
+
+    ServerArgs::try_from(staging)
+}
+
+fn main() {
+    let inputs: [&[(&str, &str)]; 7] = [
+        &[("--port", "443")],
+        &[
+            ("--port", "443"),
+            ("--tls-cert", "cert.pem"),
+            ("--tls-key", "key.pem"),
+        ],
+        &[("--tls-cert", "cert.pem")],
+        &[("--port", "https"), ("--tls-cert", "cert.pem")],
+        &[],
+        &[("--unix-socket", "/run/server.sock")],
+        &[
+            ("--unix-socket", "/run/server.sock"),
+            ("--tls-cert", "cert.pem"),
+            ("--tls-key", "key.pem"),
+        ],
+    ];
+
+    for args in inputs {
+        match parse(args) {
+            Ok(parsed) => println!("Parsed {}", parsed),
+            Err(err) => println!("Failed to parse {:?}:\n{}", args, err),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! `context` finalizes the checker with a value that validators need, here the limits of
+//! the tenant a project is created for.
+
+use staging::{Errors, Staging};
+
+struct Tenant {
+    max_members: usize,
+    reserved_names: Vec<&'static str>,
+}
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, final_error = Errors::<String>, context = Tenant)]
+struct Project {
+    #[staging(validate = available_name)]
+    name: String,
+    #[staging(validate = within_plan)]
+    members: usize,
+}
+
+fn available_name(name: &String, tenant: &Tenant) -> Result<(), String> {
+    match tenant.reserved_names.contains(&name.as_str()) {
+        true => Err(format!("`{}` is reserved", name)),
+        false => Ok(()),
+    }
+}
+
+fn within_plan(members: &usize, tenant: &Tenant) -> Result<(), String> {
+    match *members > tenant.max_members {
+        true => Err(format!("the plan allows {} members", tenant.max_members)),
+        false => Ok(()),
+    }
+}
+
+fn main() {
+    let tenant = Tenant {
+        max_members: 5,
+        reserved_names: vec!["admin"],
+    };
+
+    let errors = ProjectStaging {
+        name: Ok("admin".to_string()),
+        members: Ok(8),
+    }
+    .try_into_with(&tenant)
+    .unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        ["`admin` is reserved", "the plan allows 5 members"]
+    );
+
+    let project = ProjectStaging {
+        name: Ok("website".to_string()),
+        members: Ok(3),
+    }
+    .try_into_with(&tenant);
+    assert_eq!(
+        project.unwrap(),
+        Project {
+            name: "website".to_string(),
+            members: 3,
+        }
+    );
+    println!("Validated the project against the tenant's limits");
+}
@@ -0,0 +1,89 @@
+//! `deserialize` reads a checker from a document, such as a JSON form submission, keeping
+//! a value of the wrong type as an error on its field instead of rejecting the document.
+//! Run with `--features serde`.
+
+use std::fmt;
+
+use staging::{DeserializeError, Errors, Staging};
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    Invalid(DeserializeError),
+    TooYoung(u8),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(field) => write!(f, "`{}` is required", field),
+            Error::Invalid(err) => err.fmt(f),
+            Error::TooYoung(age) => write!(f, "must be 18 or older, not {}", age),
+        }
+    }
+}
+
+impl From<DeserializeError> for Error {
+    fn from(err: DeserializeError) -> Self {
+        Error::Invalid(err)
+    }
+}
+
+fn adult(age: &u8) -> Result<(), Error> {
+    if *age >= 18 {
+        Ok(())
+    } else {
+        Err(Error::TooYoung(*age))
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(
+    error = Error,
+    final_error = Errors::<Error>,
+    builder = Error::Missing,
+    deserialize,
+    serde(rename_all = "camelCase")
+)]
+struct Signup {
+    user_name: String,
+    #[staging(serde(alias = "mail"))]
+    email: String,
+    #[staging(validate = adult)]
+    age: u8,
+    #[staging(optional)]
+    referral_code: Option<String>,
+}
+
+fn submit(json: &str) {
+    let staging: SignupStaging = match serde_json::from_str(json) {
+        Ok(staging) => staging,
+        Err(err) => {
+            println!("Malformed form: {}", err);
+            return;
+        }
+    };
+
+    match Signup::try_from(staging) {
+        Ok(signup) => println!(
+            "Welcome {} <{}>, age {}{}",
+            signup.user_name,
+            signup.email,
+            signup.age,
+            signup
+                .referral_code
+                .map(|code| format!(", referred by {}", code))
+                .unwrap_or_default()
+        ),
+        Err(errors) => println!("Rejected form:\n{}", errors),
+    }
+}
+
+fn main() {
+    submit(
+        r#"{ "userName": "ada", "email": "ada@example.com", "age": 36, "referralCode": "XJ9" }"#,
+    );
+    submit(r#"{ "userName": "bob", "mail": "bob@example.com", "age": 17 }"#);
+    submit(r#"{ "userName": 42, "age": "old", "referralCode": null, "theme": "dark" }"#);
+    submit(r#"{ "userName": "eve", "age": "#);
+}
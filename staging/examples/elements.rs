@@ -0,0 +1,54 @@
+//! `elements` stages each element of a collection separately, so every failing element
+//! is reported along with its index.
+
+use staging::{ElementError, Errors, Staging};
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    InvalidPort(String),
+    Element(usize, Box<Error>),
+}
+
+impl From<ElementError<Error>> for Error {
+    fn from(error: ElementError<Error>) -> Self {
+        Error::Element(error.index, Box::new(error.source))
+    }
+}
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = Error, final_error = Errors::<Error>)]
+struct Listener {
+    #[staging(elements)]
+    ports: Vec<u16>,
+}
+
+fn port(value: &str) -> Result<u16, Error> {
+    value
+        .parse()
+        .map_err(|_| Error::InvalidPort(value.to_string()))
+}
+
+fn main() {
+    let listener = ListenerStaging {
+        ports: ["80", "443"].into_iter().map(port).collect(),
+    };
+    assert_eq!(
+        Listener::try_from(listener).unwrap(),
+        Listener {
+            ports: vec![80, 443]
+        }
+    );
+
+    let listener = ListenerStaging {
+        ports: ["http", "443", "-1"].into_iter().map(port).collect(),
+    };
+    let errors = Listener::try_from(listener).unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        vec![
+            Error::Element(0, Box::new(Error::InvalidPort("http".to_string()))),
+            Error::Element(2, Box::new(Error::InvalidPort("-1".to_string()))),
+        ]
+    );
+    println!("Reported each failing element with its index");
+}
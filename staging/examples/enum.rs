@@ -0,0 +1,119 @@
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+use staging::Staging;
+
+#[derive(Debug)]
+enum Error {
+    InvalidNumber(ParseIntError),
+    EmptyName,
+    UnknownShape(String),
+    Multiple(Vec<Error>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidNumber(err) => write!(f, "invalid number: {}", err),
+            Error::EmptyName => write!(f, "name must not be empty"),
+            Error::UnknownShape(shape) => write!(f, "unknown shape `{}`", shape),
+            Error::Multiple(errors) => {
+                for (i, err) in errors.iter().enumerate() {
+                    writeln!(f, "{}: {}", i + 1, err)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromIterator<Error> for Error {
+    fn from_iter<T: IntoIterator<Item = Error>>(iter: T) -> Self {
+        let errors: Vec<Error> = iter.into_iter().collect();
+        if errors.len() == 1 {
+            errors.into_iter().next().unwrap()
+        } else {
+            Error::Multiple(errors)
+        }
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(error = Error, derive(Debug))]
+enum Shape {
+    Circle { name: String, radius: u32 },
+    Square(String, u32),
+    Point,
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Shape::Circle { name, radius } => write!(f, "circle `{}` (r={})", name, radius),
+            Shape::Square(name, side) => write!(f, "square `{}` (s={})", name, side),
+            Shape::Point => write!(f, "point"),
+        }
+    }
+}
+
+fn name(s: Option<&str>) -> Result<String, Error> {
+    match s.map(str::trim) {
+        None | Some("") => Err(Error::EmptyName),
+        Some(s) => Ok(s.to_string()),
+    }
+}
+
+fn number(s: Option<&str>) -> Result<u32, Error> {
+    s.unwrap_or_default()
+        .trim()
+        .parse()
+        .map_err(Error::InvalidNumber)
+}
+
+/// Parse a comma-separated string that starts with the shape kind.
+/// This returns an error if the shape kind is unknown; otherwise field errors are
+/// staged for the selected variant.
+impl FromStr for ShapeStaging {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(',');
+        match parts.next().unwrap_or_default() {
+            "circle" => Ok(ShapeStaging::Circle {
+                name: name(parts.next()),
+                radius: number(parts.next()),
+            }),
+            "square" => Ok(ShapeStaging::Square(
+                name(parts.next()),
+                number(parts.next()),
+            )),
+            "point" => Ok(ShapeStaging::Point),
+            other => Err(Error::UnknownShape(other.to_string())),
+        }
+    }
+}
+
+impl FromStr for Shape {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(ShapeStaging::from_str(s)?)
+    }
+}
+
+fn main() {
+    let lines = [
+        "circle,unit,1",
+        "circle,,one",
+        "square,box,4",
+        "square, ,x",
+        "point",
+        "hexagon,6",
+    ];
+
+    for line in &lines {
+        match line.parse::<Shape>() {
+            Ok(shape) => println!("Parsed shape: {}", shape),
+            Err(err) => println!("Failed to parse '{}': {:?}", line, err),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! `error_storage` lets a checker collect its errors into a type other than `Vec`, such as
+//! `smallvec::SmallVec::<[Error; 4]>`. This example uses a small hand-written list that keeps
+//! its first two errors inline and only allocates for a third.
+
+use std::{fmt, iter, slice};
+
+use staging::Staging;
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    OutOfRange(u32),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(field) => write!(f, "`{}` is required", field),
+            Error::OutOfRange(value) => write!(f, "{} is out of range", value),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct InlineErrors<E> {
+    inline: [Option<E>; 2],
+    spilled: Vec<E>,
+}
+
+impl<E> Default for InlineErrors<E> {
+    fn default() -> Self {
+        Self {
+            inline: [None, None],
+            spilled: Vec::new(),
+        }
+    }
+}
+
+impl<E> Extend<E> for InlineErrors<E> {
+    fn extend<T: IntoIterator<Item = E>>(&mut self, iter: T) {
+        for error in iter {
+            match self.inline.iter_mut().find(|slot| slot.is_none()) {
+                Some(slot) => *slot = Some(error),
+                None => self.spilled.push(error),
+            }
+        }
+    }
+}
+
+impl<E> IntoIterator for InlineErrors<E> {
+    type Item = E;
+    type IntoIter =
+        iter::Chain<iter::Flatten<std::array::IntoIter<Option<E>, 2>>, std::vec::IntoIter<E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inline.into_iter().flatten().chain(self.spilled)
+    }
+}
+
+impl<'a, E> IntoIterator for &'a InlineErrors<E> {
+    type Item = &'a E;
+    type IntoIter = iter::Chain<iter::Flatten<slice::Iter<'a, Option<E>>>, slice::Iter<'a, E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inline.iter().flatten().chain(&self.spilled)
+    }
+}
+
+fn in_range(value: &u32) -> Result<(), Error> {
+    if *value <= 100 {
+        Ok(())
+    } else {
+        Err(Error::OutOfRange(*value))
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(
+    error = Error,
+    final_error = Vec::<Error>,
+    error_storage = InlineErrors::<Error>,
+    builder = Error::Missing,
+    additional_errors
+)]
+struct Request {
+    #[staging(into)]
+    path: String,
+    #[staging(validate = in_range)]
+    limit: u32,
+    #[staging(optional)]
+    offset: Option<u32>,
+}
+
+fn report(staging: RequestStaging) {
+    match staging.into_result() {
+        Ok(request) => println!(
+            "GET {}?limit={}&offset={}",
+            request.path,
+            request.limit,
+            request.offset.unwrap_or(0)
+        ),
+        Err(errors) => {
+            println!(
+                "{} errors inline, {} spilled:",
+                errors.inline.iter().flatten().count(),
+                errors.spilled.len()
+            );
+            for error in &errors {
+                println!("  {}", error);
+            }
+        }
+    }
+}
+
+fn main() {
+    let mut staging = RequestStaging::new();
+    staging.set_path("/items").set_limit(10);
+    report(staging);
+
+    let mut staging = RequestStaging::new();
+    staging.set_limit(500);
+    report(staging);
+
+    let mut staging = RequestStaging::new();
+    staging.set_limit(500);
+    staging.handle::<()>(Err(Error::Missing("token")));
+    report(staging);
+}
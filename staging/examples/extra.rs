@@ -0,0 +1,59 @@
+//! `extra` adds fields that exist only on the checker, here the line of a file that a row
+//! came from, so a `pre_finalize` check can say where a problem is.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(
+    error = String,
+    final_error = Errors::<String>,
+    missing = "missing".to_string(),
+    extra(line: usize, raw: String),
+    pre_finalize = check_row
+)]
+struct Product {
+    name: String,
+    price: u32,
+}
+
+fn check_row(row: &ProductStaging) -> Option<String> {
+    row.raw
+        .contains(";;")
+        .then(|| format!("line {}: empty column in `{}`", row.line, row.raw))
+}
+
+fn parse_row(line: usize, raw: &str) -> ProductStaging {
+    let mut row = ProductStaging::new();
+    row.line = line;
+    row.raw = raw.to_string();
+    let mut columns = raw.split(';');
+    if let Some(name) = columns.next() {
+        row.name = Ok(name.to_string());
+    }
+    if let Some(price) = columns.next() {
+        row.price = price
+            .parse()
+            .map_err(|_| format!("line {}: `{}` is not a price", line, price));
+    }
+    row
+}
+
+fn main() {
+    assert_eq!(
+        Product::try_from(parse_row(1, "tea;4")).unwrap(),
+        Product {
+            name: "tea".to_string(),
+            price: 4,
+        }
+    );
+
+    let errors = Product::try_from(parse_row(2, "cake;;5")).unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        [
+            "line 2: empty column in `cake;;5`",
+            "line 2: `` is not a price",
+        ]
+    );
+    println!("Reported problems with the line they came from");
+}
@@ -0,0 +1,46 @@
+//! `fail_fast` makes `TryFrom` return the first error it finds, for callers that report
+//! one problem at a time. `into_result` still collects every error.
+
+use staging::Staging;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Error {
+    Empty(&'static str),
+    TooLong(&'static str),
+}
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = Error, fail_fast, derive(Clone))]
+struct Tweet {
+    author: String,
+    text: String,
+}
+
+fn main() {
+    let checker = TweetStaging {
+        author: Err(Error::Empty("author")),
+        text: Err(Error::TooLong("text")),
+    };
+
+    assert_eq!(
+        Tweet::try_from(checker.clone()).unwrap_err(),
+        Error::Empty("author")
+    );
+    assert_eq!(
+        checker.into_result().unwrap_err(),
+        vec![Error::Empty("author"), Error::TooLong("text")]
+    );
+
+    let tweet = Tweet::try_from(TweetStaging {
+        author: Ok("ferris".to_string()),
+        text: Ok("Hello!".to_string()),
+    });
+    assert_eq!(
+        tweet.unwrap(),
+        Tweet {
+            author: "ferris".to_string(),
+            text: "Hello!".to_string(),
+        }
+    );
+    println!("Stopped at the first error");
+}
@@ -0,0 +1,98 @@
+//! `field_type = Field` stores each field of the checker as a `Field`, whose combinators
+//! build a value up from raw input one step at a time, keeping the first error found.
+
+use std::fmt;
+
+use staging::{Accumulator, Errors, Field, FieldError, Staging};
+
+#[derive(Debug)]
+enum Problem {
+    Empty,
+    NotANumber(String),
+    TooYoung(u8),
+    InvalidTag(String),
+    Tags(Errors<Problem>),
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::Empty => write!(f, "a value is required"),
+            Problem::NotANumber(value) => write!(f, "`{}` is not a number", value),
+            Problem::TooYoung(age) => write!(f, "{} is under the minimum age of 13", age),
+            Problem::InvalidTag(tag) => write!(f, "`{}` is not a valid tag", tag),
+            Problem::Tags(problems) => {
+                let problems: Vec<String> = problems.iter().map(Problem::to_string).collect();
+                write!(f, "{}", problems.join(", "))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(
+    error = FieldError::<Problem>,
+    final_error = Errors::<FieldError<Problem>>,
+    field_type = Field
+)]
+struct Signup {
+    username: String,
+    age: u8,
+    tags: Vec<String>,
+}
+
+fn required(value: &str) -> Field<String, Problem> {
+    match value.trim() {
+        "" => Field::err(Problem::Empty),
+        value => Field::ok(value.to_string()),
+    }
+}
+
+fn tag(tag: &str) -> Result<String, Problem> {
+    let tag = tag.trim();
+    if !tag.is_empty() && tag.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        Ok(tag.to_lowercase())
+    } else {
+        Err(Problem::InvalidTag(tag.to_string()))
+    }
+}
+
+/// Stage a signup form, labelling each field's error with its name.
+fn check_signup(username: &str, age: &str, tags: &str) -> SignupStaging {
+    SignupStaging {
+        username: required(username)
+            .map(|username| username.to_lowercase())
+            .with_context("username"),
+        age: required(age)
+            .and_then(|age| age.parse::<u8>().map_err(|_| Problem::NotANumber(age)))
+            .require(|age| *age >= 13, |age| Problem::TooYoung(*age))
+            .with_context("age"),
+        tags: Field::ok(tags)
+            .and_then_collect(|tags| {
+                let mut problems = Accumulator::new();
+                let tags: Vec<String> = tags
+                    .split(',')
+                    .filter_map(|value| problems.handle(tag(value)))
+                    .collect();
+                problems.finish_with(|| tags)
+            })
+            .map_err(Problem::Tags)
+            .with_context("tags"),
+    }
+}
+
+fn main() {
+    for (username, age, tags) in [
+        ("Ferris", "16", "rust, crab"),
+        ("", "twelve", "rust"),
+        ("Corro", "9", "unsafe code, c++"),
+    ] {
+        match Signup::try_from(check_signup(username, age, tags)) {
+            Ok(signup) => println!(
+                "{} ({}) tagged {:?}",
+                signup.username, signup.age, signup.tags
+            ),
+            Err(errors) => println!("Invalid signup:\n{}", errors),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! `field_enum` emits an enum with a variant for each field, a typed key for errors and
+//! lookups in place of the field's name as a string.
+
+use std::collections::BTreeMap;
+
+use staging::Staging;
+
+#[derive(Debug, Staging)]
+#[staging(error = String, field_enum)]
+struct Signup {
+    user_name: String,
+    age: u8,
+    #[staging(skip)]
+    created: u64,
+}
+
+fn main() {
+    assert_eq!(SignupField::UserName.as_str(), "user_name");
+    assert_eq!(SignupField::Age.to_string(), "age");
+
+    // Errors keyed by field, as a form would show them next to each input.
+    let mut errors = BTreeMap::new();
+    errors.insert(SignupField::Age, "must be at least 13");
+    errors.insert(SignupField::UserName, "is already taken");
+    let report: Vec<String> = errors
+        .iter()
+        .map(|(field, error)| format!("{} {}", field, error))
+        .collect();
+    assert_eq!(
+        report,
+        ["user_name is already taken", "age must be at least 13"]
+    );
+
+    let signup = Signup::try_from(SignupStaging {
+        user_name: Ok("ferris".to_string()),
+        age: Ok(16),
+    })
+    .unwrap();
+    assert_eq!(
+        (signup.user_name.as_str(), signup.age, signup.created),
+        ("ferris", 16, 0)
+    );
+    println!("{}", report.join("\n"));
+}
@@ -0,0 +1,46 @@
+//! `field_errors` wraps each error in a `FieldError` naming the field it came from, so a
+//! report can point at the field without each error type recording it.
+
+use staging::{Errors, FieldError, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(
+    error = String,
+    final_error = Errors::<FieldError<String>>,
+    field_errors
+)]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+fn main() {
+    let errors = Server::try_from(ServerStaging {
+        host: Ok("localhost".to_string()),
+        port: Err("`http` is not a number".to_string()),
+    })
+    .unwrap_err();
+
+    let errors: Vec<FieldError<String>> = errors.into_iter().collect();
+    assert_eq!(
+        errors,
+        vec![FieldError::new(
+            "port",
+            "`http` is not a number".to_string()
+        )]
+    );
+    assert_eq!(errors[0].to_string(), "`port`: `http` is not a number");
+
+    let server = Server::try_from(ServerStaging {
+        host: Ok("localhost".to_string()),
+        port: Ok(8080),
+    });
+    assert_eq!(
+        server.unwrap(),
+        Server {
+            host: "localhost".to_string(),
+            port: 8080,
+        }
+    );
+    println!("Named the field each error came from");
+}
@@ -0,0 +1,54 @@
+//! `field_vis` sets the visibility of every checker field, and a field's own `vis`
+//! overrides it. Here only the name can be set directly from outside the module; the
+//! API key goes through a function that checks it.
+
+mod settings {
+    use staging::{Errors, Staging};
+
+    #[derive(Debug, PartialEq, Staging)]
+    #[staging(
+        error = String,
+        final_error = Errors::<String>,
+        missing = "missing".to_string(),
+        field_vis = "pub(self)"
+    )]
+    pub struct Settings {
+        #[staging(vis = "pub")]
+        pub name: String,
+        pub api_key: String,
+    }
+
+    impl SettingsStaging {
+        /// Store the API key if it looks like one.
+        pub fn api_key(&mut self, key: &str) {
+            self.api_key = match key.starts_with("sk-") {
+                true => Ok(key.to_string()),
+                false => Err(format!("`{}` is not an API key", key)),
+            };
+        }
+    }
+}
+
+use settings::{Settings, SettingsStaging};
+
+fn main() {
+    let mut checker = SettingsStaging::new();
+    checker.name = Ok("production".to_string());
+    checker.api_key("sk-123");
+    assert_eq!(
+        Settings::try_from(checker).unwrap(),
+        Settings {
+            name: "production".to_string(),
+            api_key: "sk-123".to_string(),
+        }
+    );
+
+    let mut checker = SettingsStaging::new();
+    checker.api_key("hunter2");
+    let errors = Settings::try_from(checker).unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        ["missing", "`hunter2` is not an API key"]
+    );
+    println!("Set the private field through the checker's own method");
+}
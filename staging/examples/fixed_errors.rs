@@ -0,0 +1,133 @@
+//! `error_storage(container = ..., overflow = ...)` collects errors into a list with a fixed
+//! capacity, such as `heapless::Vec::<Error, 4>`, so finalizing never allocates. Once the list
+//! is full, its last error is replaced with the overflow error.
+
+use std::{array, fmt, iter, slice};
+
+use staging::Staging;
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    OutOfRange(i32),
+    TooManyErrors,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(field) => write!(f, "`{}` is required", field),
+            Error::OutOfRange(value) => write!(f, "{}% is out of range", value),
+            Error::TooManyErrors => write!(f, "too many errors"),
+        }
+    }
+}
+
+/// A list of at most `N` errors, with the same `push` and `pop` as `heapless::Vec`.
+struct FixedErrors<E, const N: usize> {
+    slots: [Option<E>; N],
+    len: usize,
+}
+
+impl<E, const N: usize> FixedErrors<E, N> {
+    fn push(&mut self, error: E) -> Result<(), E> {
+        match self.slots.get_mut(self.len) {
+            Some(slot) => {
+                *slot = Some(error);
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(error),
+        }
+    }
+
+    fn pop(&mut self) -> Option<E> {
+        self.len = self.len.checked_sub(1)?;
+        self.slots[self.len].take()
+    }
+}
+
+impl<E, const N: usize> Default for FixedErrors<E, N> {
+    fn default() -> Self {
+        Self {
+            slots: array::from_fn(|_| None),
+            len: 0,
+        }
+    }
+}
+
+impl<E, const N: usize> IntoIterator for FixedErrors<E, N> {
+    type Item = E;
+    type IntoIter = iter::Flatten<array::IntoIter<Option<E>, N>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slots.into_iter().flatten()
+    }
+}
+
+impl<'a, E, const N: usize> IntoIterator for &'a FixedErrors<E, N> {
+    type Item = &'a E;
+    type IntoIter = iter::Flatten<slice::Iter<'a, Option<E>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slots.iter().flatten()
+    }
+}
+
+fn percent(value: &i32) -> Result<(), Error> {
+    if (0..=100).contains(value) {
+        Ok(())
+    } else {
+        Err(Error::OutOfRange(*value))
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(
+    error = Error,
+    final_error = FixedErrors::<Error, 3>,
+    combine = std::convert::identity,
+    error_storage(container = FixedErrors::<Error, 3>, overflow = Error::TooManyErrors),
+    builder = Error::Missing
+)]
+struct Reading {
+    sensor: u8,
+    #[staging(validate = percent)]
+    humidity: i32,
+    #[staging(validate = percent)]
+    battery: i32,
+    interval: u16,
+}
+
+fn report(staging: ReadingStaging) {
+    match Reading::try_from(staging) {
+        Ok(reading) => println!(
+            "Sensor {}: {}% humidity, {}% battery, every {}s",
+            reading.sensor, reading.humidity, reading.battery, reading.interval
+        ),
+        Err(errors) => {
+            println!("Invalid reading:");
+            for error in errors {
+                println!("  {}", error);
+            }
+        }
+    }
+}
+
+fn main() {
+    let mut staging = ReadingStaging::new();
+    staging
+        .set_sensor(4)
+        .set_humidity(40)
+        .set_battery(90)
+        .set_interval(60);
+    report(staging);
+
+    let mut staging = ReadingStaging::new();
+    staging.set_sensor(4).set_humidity(140);
+    report(staging);
+
+    let mut staging = ReadingStaging::new();
+    staging.set_humidity(140).set_battery(-5);
+    report(staging);
+}
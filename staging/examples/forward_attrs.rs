@@ -0,0 +1,38 @@
+//! `forward_attrs` copies the named attributes from each field onto its checker field, so
+//! a checker that derives `Serialize` writes its fields under the same keys as the target.
+
+use serde::Serialize;
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq, Serialize, Staging)]
+#[staging(
+    error = String,
+    final_error = Errors::<String>,
+    derive(Serialize),
+    forward_attrs(serde)
+)]
+struct Profile {
+    #[serde(rename = "displayName")]
+    display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[staging(optional)]
+    website: Option<String>,
+}
+
+fn main() {
+    let checker = ProfileStaging {
+        display_name: Ok("Ferris".to_string()),
+        website: None,
+    };
+    assert_eq!(
+        serde_json::to_string(&checker).unwrap(),
+        r#"{"displayName":{"Ok":"Ferris"}}"#
+    );
+
+    let profile = Profile::try_from(checker).unwrap();
+    assert_eq!(
+        serde_json::to_string(&profile).unwrap(),
+        r#"{"displayName":"Ferris"}"#
+    );
+    println!("Serialized the checker with the forwarded attributes");
+}
@@ -0,0 +1,67 @@
+//! `from_staging` finalizes the checker through `FromStaging`, here in place of `TryFrom`
+//! so that the type's only `TryFrom` impl is the one that parses it from a string.
+
+use std::fmt;
+
+use staging::{FromStaging, IntoFinal, Staging};
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    Format(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(field) => write!(f, "`{}` is required", field),
+            Error::Format(value) => write!(f, "`{}` is not `major.minor`", value),
+        }
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(
+    error = Error,
+    final_error = Vec::<Error>,
+    builder = Error::Missing,
+    no_try_from,
+    from_staging
+)]
+struct Version {
+    major: u32,
+    minor: u32,
+}
+
+impl TryFrom<&str> for Version {
+    type Error = Vec<Error>;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut staging = VersionStaging::new();
+        let format = || Error::Format(value.to_string());
+        match value.split_once('.') {
+            Some((major, minor)) => {
+                staging.major = major.parse().map_err(|_| format());
+                staging.minor = minor.parse().map_err(|_| format());
+            }
+            None => staging.major = Err(format()),
+        }
+        Version::from_staging(staging)
+    }
+}
+
+fn main() {
+    for value in ["1.4", "1.x", "latest"] {
+        match Version::try_from(value) {
+            Ok(version) => println!("Version {}.{}", version.major, version.minor),
+            Err(errors) => println!("`{}`: {} errors", value, errors.len()),
+        }
+    }
+
+    let mut staging = VersionStaging::new();
+    staging.set_major(2);
+    let version: Result<Version, _> = staging.into_final();
+    for error in version.unwrap_err() {
+        println!("{}", error);
+    }
+}
@@ -0,0 +1,93 @@
+//! `generic_error` makes the checker generic over its error type. `map_errors` converts
+//! every error a checker holds, so values checked with a simple error such as a message
+//! can be reported in the caller's error type.
+
+use std::{fmt, num::ParseIntError};
+
+use staging::{Errors, Staging};
+
+#[derive(Debug)]
+struct ConfigError {
+    file: &'static str,
+    message: String,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.file, self.message)
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(generic_error, final_error = Errors::<E>)]
+struct Limits {
+    max_connections: u32,
+}
+
+#[derive(Debug, Staging)]
+#[staging(generic_error, final_error = Errors::<E>, additional_errors)]
+struct Server {
+    host: String,
+    port: u16,
+    aliases: Vec<String>,
+    #[staging(optional)]
+    timeout: Option<u64>,
+    #[staging(nested(checker = LimitsStaging::<E>, merge_errors))]
+    limits: Limits,
+}
+
+fn number<T: std::str::FromStr<Err = ParseIntError>>(value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|err| format!("`{}` is not a valid number: {}", value, err))
+}
+
+/// Check the values of a server, recording each problem as a message.
+fn check_server(port: &str, aliases: &[&str], connections: &str) -> ServerStaging<String> {
+    let mut additional_errors = vec![];
+    if aliases.is_empty() {
+        additional_errors.push("at least one alias is expected".to_string());
+    }
+
+    ServerStaging {
+        host: Ok("example.com".to_string()),
+        port: number(port),
+        aliases: aliases
+            .iter()
+            .map(|alias| match alias.contains(' ') {
+                true => Err(format!("`{}` is not a host name", alias)),
+                false => Ok(alias.to_string()),
+            })
+            .collect::<Result<_, _>>(),
+        timeout: Some(number("30")),
+        limits: LimitsStaging {
+            max_connections: number(connections),
+        },
+        additional_errors,
+    }
+}
+
+fn main() {
+    for (port, aliases, connections) in [
+        ("8080", &["www.example.com"][..], "100"),
+        ("http", &["www example com"][..], "-1"),
+        ("443", &[][..], "50"),
+    ] {
+        let checker = check_server(port, aliases, connections).map_errors(|message| ConfigError {
+            file: "server.toml",
+            message,
+        });
+
+        match Server::try_from(checker) {
+            Ok(server) => println!(
+                "{}:{} as {:?}, timing out after {}s with up to {} connections",
+                server.host,
+                server.port,
+                server.aliases,
+                server.timeout.unwrap_or(60),
+                server.limits.max_connections
+            ),
+            Err(errors) => println!("Invalid server:\n{}", errors),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use std::{fmt, str::FromStr};
+
+use staging::Staging;
+
+#[derive(Debug)]
+struct Error(Vec<String>);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.join("; "))
+    }
+}
+
+impl FromIterator<String> for Error {
+    fn from_iter<T: IntoIterator<Item = String>>(iter: T) -> Self {
+        Error(iter.into_iter().collect())
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(error = String, final_error = Error, derive(Debug))]
+struct Setting<'a, T: FromStr>
+where
+    T: fmt::Debug,
+{
+    key: &'a str,
+    value: T,
+}
+
+#[derive(Debug, Staging)]
+#[staging(error = String, final_error = Error)]
+struct Buf<const N: usize> {
+    data: [u8; N],
+    label: String,
+}
+
+fn buf<const N: usize>(label: &str, bytes: &[u8]) -> Result<Buf<N>, Error> {
+    Buf::try_from(BufStaging {
+        data: bytes
+            .try_into()
+            .map_err(|_| format!("expected {} bytes, got {}", N, bytes.len())),
+        label: match label {
+            "" => Err("missing label".to_string()),
+            label => Ok(label.to_string()),
+        },
+    })
+}
+
+fn parse<'a, T>(line: &'a str) -> Result<Setting<'a, T>, Error>
+where
+    T: FromStr + fmt::Debug,
+{
+    let (key, value) = line.split_once('=').unwrap_or((line, ""));
+    let staging = SettingStaging {
+        key: match key.trim() {
+            "" => Err("missing key".to_string()),
+            key => Ok(key),
+        },
+        value: value
+            .trim()
+            .parse::<T>()
+            .map_err(|_| format!("invalid value `{}`", value.trim())),
+    };
+
+    Setting::try_from(staging)
+}
+
+fn main() {
+    for line in ["port = 8080", "= 80", "port = eighty", "=x"] {
+        match parse::<u16>(line) {
+            Ok(setting) => println!("Parsed {} = {:?}", setting.key, setting.value),
+            Err(err) => println!("Failed to parse '{}': {}", line, err),
+        }
+    }
+
+    for (label, bytes) in [("pair", &[1u8, 2][..]), ("", &[1, 2, 3][..])] {
+        match buf::<2>(label, bytes) {
+            Ok(buf) => println!("Built {} = {:?}", buf.label, buf.data),
+            Err(err) => println!("Failed to build buffer: {}", err),
+        }
+    }
+}
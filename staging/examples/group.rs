@@ -0,0 +1,64 @@
+//! `group` labels the errors of related fields, so `errors_by_group` can report a large
+//! configuration section by section.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, final_error = Errors::<String>)]
+struct Config {
+    #[staging(group = "network")]
+    host: String,
+    #[staging(group = "network")]
+    port: u16,
+    #[staging(group = "storage")]
+    data_dir: String,
+    log_level: String,
+}
+
+fn main() {
+    let checker = ConfigStaging {
+        host: Ok("localhost".to_string()),
+        port: Ok(8080),
+        data_dir: Ok("/var/lib/app".to_string()),
+        log_level: Ok("info".to_string()),
+    };
+    assert_eq!(
+        checker.errors_by_group().unwrap(),
+        Config {
+            host: "localhost".to_string(),
+            port: 8080,
+            data_dir: "/var/lib/app".to_string(),
+            log_level: "info".to_string(),
+        }
+    );
+
+    let checker = ConfigStaging {
+        host: Err("host is empty".to_string()),
+        port: Err("port 0 is reserved".to_string()),
+        data_dir: Err("/data does not exist".to_string()),
+        log_level: Err("`loud` is not a log level".to_string()),
+    };
+
+    let groups = checker.errors_by_group().unwrap_err();
+    assert_eq!(
+        groups,
+        vec![
+            (
+                Some("network"),
+                vec![
+                    "host is empty".to_string(),
+                    "port 0 is reserved".to_string()
+                ]
+            ),
+            (Some("storage"), vec!["/data does not exist".to_string()]),
+            (None, vec!["`loud` is not a log level".to_string()]),
+        ]
+    );
+
+    for (group, errors) in groups {
+        println!("[{}]", group.unwrap_or("general"));
+        for error in errors {
+            println!("  {}", error);
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! `doc_hidden` leaves the checker out of the generated docs, and `automatically_derived`
+//! marks its impls as derived code. Neither changes how the checker is used.
+
+pub mod config {
+    use staging::{Errors, Staging};
+
+    /// Settings for connecting to a server.
+    #[derive(Debug, PartialEq, Staging)]
+    #[staging(
+        error = String,
+        final_error = Errors::<String>,
+        doc_hidden,
+        automatically_derived
+    )]
+    pub struct Server {
+        pub host: String,
+        pub port: u16,
+    }
+}
+
+use config::{Server, ServerStaging};
+
+fn main() {
+    let server = Server::try_from(ServerStaging {
+        host: Ok("localhost".to_string()),
+        port: "8080".parse().map_err(|_| "not a port".to_string()),
+    })
+    .unwrap();
+    assert_eq!(
+        server,
+        Server {
+            host: "localhost".to_string(),
+            port: 8080,
+        }
+    );
+
+    let errors = Server::try_from(ServerStaging {
+        host: Err("a host is required".to_string()),
+        port: "http".parse().map_err(|_| "not a port".to_string()),
+    })
+    .unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        ["a host is required", "not a port"]
+    );
+    println!("Used a hidden checker like any other");
+}
@@ -0,0 +1,59 @@
+//! `map_err` runs a function on a field's error before it is recorded, here to note which
+//! file the value came from without changing the field's error type.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    NotANumber(String),
+    InFile(&'static str, Box<Error>),
+}
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = Error, final_error = Errors::<Error>)]
+struct Limits {
+    #[staging(map_err = in_limits_file)]
+    max_users: u32,
+    max_rooms: u32,
+}
+
+fn in_limits_file(error: Error) -> Error {
+    Error::InFile("limits.toml", Box::new(error))
+}
+
+fn number(value: &str) -> Result<u32, Error> {
+    value
+        .parse()
+        .map_err(|_| Error::NotANumber(value.to_string()))
+}
+
+fn main() {
+    let errors = Limits::try_from(LimitsStaging {
+        max_users: number("lots"),
+        max_rooms: number("few"),
+    })
+    .unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        vec![
+            Error::InFile(
+                "limits.toml",
+                Box::new(Error::NotANumber("lots".to_string()))
+            ),
+            Error::NotANumber("few".to_string()),
+        ]
+    );
+
+    let limits = Limits::try_from(LimitsStaging {
+        max_users: number("100"),
+        max_rooms: number("10"),
+    });
+    assert_eq!(
+        limits.unwrap(),
+        Limits {
+            max_users: 100,
+            max_rooms: 10,
+        }
+    );
+    println!("Attached the file name to the mapped field's error");
+}
@@ -0,0 +1,50 @@
+//! `map_errors` converts every error a `generic_error` checker holds, so a checker filled
+//! in by a library with its own error type can be finalized with the caller's.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq)]
+struct AppError {
+    source: &'static str,
+    message: String,
+}
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(generic_error, final_error = Errors::<E>, additional_errors)]
+struct Account {
+    name: String,
+    credits: u32,
+}
+
+/// A library that reports its problems as plain messages.
+fn parse_account(name: &str, credits: &str) -> AccountStaging<String> {
+    AccountStaging {
+        name: Ok(name.to_string()),
+        credits: credits
+            .parse()
+            .map_err(|_| format!("`{}` is not a number of credits", credits)),
+        additional_errors: vec!["the account is locked".to_string()],
+    }
+}
+
+fn main() {
+    let checker = parse_account("ada", "many").map_errors(|message| AppError {
+        source: "accounts.csv",
+        message,
+    });
+    let errors = Account::try_from(checker).unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        vec![
+            AppError {
+                source: "accounts.csv",
+                message: "the account is locked".to_string(),
+            },
+            AppError {
+                source: "accounts.csv",
+                message: "`many` is not a number of credits".to_string(),
+            },
+        ]
+    );
+    println!("Converted every error to the application's error type");
+}
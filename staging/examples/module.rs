@@ -0,0 +1,43 @@
+//! `module` places the checker and everything generated with it in a module of its own,
+//! which keeps the namespace tidy when many types derive `Staging`. With `reexport`, the
+//! checker is also available next to the deriving type.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, final_error = Errors::<String>, module = "user_staging")]
+struct User {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(
+    error = String,
+    final_error = Errors::<String>,
+    module(name = "team_staging", reexport)
+)]
+struct Team {
+    title: String,
+}
+
+fn main() {
+    let user = User::try_from(user_staging::UserStaging {
+        name: Ok("Ferris".to_string()),
+    });
+    assert_eq!(
+        user.unwrap(),
+        User {
+            name: "Ferris".to_string(),
+        }
+    );
+
+    let team = Team::try_from(TeamStaging {
+        title: Err("a title is required".to_string()),
+    });
+    assert_eq!(
+        team.unwrap_err().into_iter().collect::<Vec<_>>(),
+        ["a title is required"]
+    );
+    assert_eq!(team_staging::TeamStaging::FIELDS, ["title"]);
+    println!("Used the checkers from their modules");
+}
@@ -0,0 +1,55 @@
+//! `multiple_errors` lets a field hold several problems at once, each of which is
+//! reported when the checker is finalized.
+
+use staging::{AtLeastOne, Errors, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, final_error = Errors::<String>)]
+struct Account {
+    #[staging(multiple_errors)]
+    user_name: String,
+    email: String,
+}
+
+fn user_name(value: &str) -> Result<String, AtLeastOne<String>> {
+    let mut problems = vec![];
+    if value.len() > 8 {
+        problems.push("is longer than 8 characters".to_string());
+    }
+    if !value.chars().all(|c| c.is_ascii_alphanumeric()) {
+        problems.push("contains characters other than letters and digits".to_string());
+    }
+    match AtLeastOne::try_from(problems) {
+        Ok(problems) => Err(problems),
+        Err(_) => Ok(value.to_string()),
+    }
+}
+
+fn main() {
+    let errors = Account::try_from(AccountStaging {
+        user_name: user_name("ferris the crab"),
+        email: Err("email is required".to_string()),
+    })
+    .unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        [
+            "is longer than 8 characters",
+            "contains characters other than letters and digits",
+            "email is required",
+        ]
+    );
+
+    let account = Account::try_from(AccountStaging {
+        user_name: user_name("ferris"),
+        email: Ok("ferris@example.com".to_string()),
+    });
+    assert_eq!(
+        account.unwrap(),
+        Account {
+            user_name: "ferris".to_string(),
+            email: "ferris@example.com".to_string(),
+        }
+    );
+    println!("Reported both problems with the user name");
+}
@@ -114,10 +114,10 @@ impl FromStr for ArgsStaging {
 
         if let Ok(n) = &name
             && let Ok(a) = age
+            && n == "Mildred"
+            && a < 80
         {
-            if n == "Mildred" && a < 80 {
-                additional_errors.push(Error::NameAgeMismatch);
-            }
+            additional_errors.push(Error::NameAgeMismatch);
         }
 
         Ok(ArgsStaging {
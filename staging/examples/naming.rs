@@ -0,0 +1,39 @@
+//! `prefix` and `suffix` name the checker after the deriving type with other text around
+//! it, in place of the default `Staging` suffix.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, final_error = Errors::<String>, suffix = "Draft")]
+struct Post {
+    title: String,
+}
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, final_error = Errors::<String>, prefix = "Raw", suffix = "")]
+struct Comment {
+    text: String,
+}
+
+fn main() {
+    let post = Post::try_from(PostDraft {
+        title: Ok("Hello".to_string()),
+    });
+    assert_eq!(
+        post.unwrap(),
+        Post {
+            title: "Hello".to_string(),
+        }
+    );
+
+    let comment = Comment::try_from(RawComment {
+        text: Ok("Nice post!".to_string()),
+    });
+    assert_eq!(
+        comment.unwrap(),
+        Comment {
+            text: "Nice post!".to_string(),
+        }
+    );
+    println!("Named the checkers PostDraft and RawComment");
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+
+use staging::Staging;
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    Address(Vec<Error>),
+    Multiple(Vec<Error>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(field) => write!(f, "missing {}", field),
+            Error::Address(errors) => write!(f, "invalid address ({} problems)", errors.len()),
+            Error::Multiple(errors) => {
+                for (i, err) in errors.iter().enumerate() {
+                    writeln!(f, "{}: {}", i + 1, err)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromIterator<Error> for Error {
+    fn from_iter<T: IntoIterator<Item = Error>>(iter: T) -> Self {
+        let errors: Vec<Error> = iter.into_iter().collect();
+        if errors.len() == 1 {
+            errors.into_iter().next().unwrap()
+        } else {
+            Error::Multiple(errors)
+        }
+    }
+}
+
+/// Wrapper so that all address problems are reported as a single error.
+struct AddressError(Vec<Error>);
+
+impl FromIterator<Error> for AddressError {
+    fn from_iter<T: IntoIterator<Item = Error>>(iter: T) -> Self {
+        AddressError(iter.into_iter().collect())
+    }
+}
+
+impl From<AddressError> for Error {
+    fn from(err: AddressError) -> Self {
+        Error::Address(err.0)
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(error = Error, final_error = AddressError)]
+struct Address {
+    street: String,
+    city: String,
+}
+
+#[derive(Debug, Staging)]
+#[staging(error = Error)]
+struct Contact {
+    phone: String,
+    email: String,
+}
+
+#[derive(Debug, Staging)]
+#[staging(error = Error)]
+struct Person {
+    name: String,
+    /// Address errors are folded into a single `Error::Address`.
+    #[staging(nested)]
+    address: Address,
+    /// Contact errors are reported individually, alongside the person's own errors.
+    #[staging(nested(merge_errors))]
+    contact: Contact,
+}
+
+fn required(value: &str, field: &'static str) -> Result<String, Error> {
+    match value {
+        "" => Err(Error::Missing(field)),
+        value => Ok(value.to_string()),
+    }
+}
+
+fn person(fields: [&str; 5]) -> Result<Person, Error> {
+    let [name, street, city, phone, email] = fields;
+    Person::try_from(PersonStaging {
+        name: required(name, "name"),
+        address: AddressStaging {
+            street: required(street, "street"),
+            city: required(city, "city"),
+        },
+        contact: ContactStaging {
+            phone: required(phone, "phone"),
+            email: required(email, "email"),
+        },
+    })
+}
+
+fn main() {
+    let inputs = [
+        [
+            "Alice",
+            "1 Main St",
+            "Springfield",
+            "555-0100",
+            "alice@example.com",
+        ],
+        ["Bob", "", "", "555-0101", ""],
+        ["", "2 Side St", "", "", ""],
+    ];
+
+    for input in inputs {
+        match person(input) {
+            Ok(person) => println!(
+                "Parsed {} at {}, {} ({} / {})",
+                person.name,
+                person.address.street,
+                person.address.city,
+                person.contact.phone,
+                person.contact.email
+            ),
+            Err(err) => println!("Failed to build {:?}:\n{}", input, err),
+        }
+    }
+}
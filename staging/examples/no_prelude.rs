@@ -0,0 +1,127 @@
+//! The generated code names everything through `staging`, so it compiles in a module
+//! without the prelude, or one that defines its own `Some`, `Ok` or `Vec`.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    Missing(&'static str),
+    Empty,
+    Element(usize, Box<Error>),
+}
+
+impl From<staging::ElementError<Error>> for Error {
+    fn from(error: staging::ElementError<Error>) -> Self {
+        Error::Element(error.index, Box::new(error.source))
+    }
+}
+
+pub fn not_empty(value: &str) -> Result<(), Error> {
+    if value.is_empty() {
+        Err(Error::Empty)
+    } else {
+        Ok(())
+    }
+}
+
+#[no_implicit_prelude]
+mod model {
+    use ::std::collections::BTreeMap;
+    use ::std::option::Option;
+    use ::std::string::String;
+    use ::std::vec::Vec;
+
+    use ::staging::{Errors, Staging};
+
+    use super::Error;
+
+    #[allow(dead_code)]
+    pub struct Some;
+    #[allow(dead_code)]
+    pub struct None;
+    #[allow(dead_code)]
+    pub struct Ok;
+    #[allow(dead_code)]
+    pub struct Err;
+
+    #[derive(Debug, Staging)]
+    #[staging(
+        error = Error,
+        final_error = Errors::<Error>,
+        derive(Debug, Clone),
+        builder = Error::Missing,
+        additional_errors,
+        dedup,
+        accessors,
+        inspect,
+        merge,
+        from_target,
+        at_least_one_of(nickname, tags, error = Error::Missing("nickname or tags"))
+    )]
+    pub struct Profile {
+        #[staging(into, validate = super::not_empty)]
+        pub name: String,
+        #[staging(optional, into)]
+        pub nickname: Option<String>,
+        #[staging(elements)]
+        pub tags: Vec<String>,
+        #[staging(entries)]
+        pub links: BTreeMap<String, String>,
+        #[staging(default)]
+        pub age: u8,
+        #[staging(infallible)]
+        pub admin: bool,
+    }
+
+    #[derive(Debug, Staging)]
+    #[staging(error = Error, final_error = Vec::<Error>, derive(Debug))]
+    pub enum Contact {
+        Email(String),
+        Phone {
+            number: String,
+            #[staging(optional)]
+            extension: Option<u16>,
+        },
+    }
+}
+
+use model::{Contact, ContactStaging, Profile, ProfileStaging};
+
+fn main() {
+    let mut staging = ProfileStaging::new();
+    staging
+        .set_name("Alice")
+        .set_tags(vec!["admin".to_string()])
+        .set_admin(true);
+    staging.tags = vec![Ok("admin".to_string()), Err(Error::Empty)];
+    println!("First error: {:?}", staging.first_error());
+    println!("Alone: {:?}", Profile::try_from(staging.clone()));
+
+    let defaults = ProfileStaging::from(Profile {
+        name: "Bob".to_string(),
+        nickname: Some("bobby".to_string()),
+        tags: vec![],
+        links: Default::default(),
+        age: 30,
+        admin: false,
+    });
+    println!(
+        "Over defaults: {:?}",
+        Profile::try_from(defaults.merge(staging))
+    );
+
+    let contacts = [
+        ContactStaging::Email(Ok("alice@example.com".to_string())),
+        ContactStaging::Phone {
+            number: Ok("555-0100".to_string()),
+            extension: Some(Err(Error::Empty)),
+        },
+    ];
+    for contact in contacts {
+        match Contact::try_from(contact) {
+            Ok(Contact::Email(email)) => println!("Email {}", email),
+            Ok(Contact::Phone { number, extension }) => {
+                println!("Phone {} ext. {:?}", number, extension)
+            }
+            Err(errors) => println!("Invalid contact: {:?}", errors),
+        }
+    }
+}
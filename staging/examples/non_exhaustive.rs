@@ -0,0 +1,44 @@
+//! `non_exhaustive` keeps other crates from creating the checker with a struct expression,
+//! so fields can be added later. They create it with `new()` from `missing` instead, and
+//! fill it in with the `setters` methods or `stage!`.
+
+use staging::{Errors, Staging, stage};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(
+    error = String,
+    final_error = Errors::<String>,
+    non_exhaustive,
+    missing = "a value is required".to_string(),
+    setters
+)]
+pub struct Contact {
+    pub name: String,
+    pub email: String,
+}
+
+fn main() {
+    let mut checker = ContactStaging::new();
+    checker.set_name("Ferris".to_string());
+    assert_eq!(
+        Contact::try_from(checker)
+            .unwrap_err()
+            .into_iter()
+            .collect::<Vec<_>>(),
+        ["a value is required"]
+    );
+
+    let email = "ferris@example.com".to_string();
+    let contact = Contact::try_from(stage! {
+        ContactStaging { name: Ok("Ferris".to_string()), email: Ok(email) }
+    })
+    .unwrap();
+    assert_eq!(
+        contact,
+        Contact {
+            name: "Ferris".to_string(),
+            email: "ferris@example.com".to_string(),
+        }
+    );
+    println!("Created a non-exhaustive checker without a struct expression");
+}
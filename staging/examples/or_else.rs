@@ -0,0 +1,48 @@
+//! `or_else` gives a field a chance to recover a value from its error before the error is
+//! recorded, here by parsing a duration again with a more lenient format.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    InvalidTimeout(String),
+}
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = Error, final_error = Errors::<Error>)]
+struct Options {
+    #[staging(or_else = lenient_seconds)]
+    timeout: u64,
+}
+
+/// Accept a number of seconds written with a trailing `s`, such as `30s`.
+fn lenient_seconds(error: Error) -> Result<u64, Error> {
+    let Error::InvalidTimeout(value) = &error;
+    value
+        .strip_suffix('s')
+        .and_then(|seconds| seconds.parse().ok())
+        .ok_or(error)
+}
+
+fn timeout(value: &str) -> Result<u64, Error> {
+    value
+        .parse()
+        .map_err(|_| Error::InvalidTimeout(value.to_string()))
+}
+
+fn main() {
+    let options = Options::try_from(OptionsStaging {
+        timeout: timeout("30s"),
+    });
+    assert_eq!(options.unwrap(), Options { timeout: 30 });
+
+    let errors = Options::try_from(OptionsStaging {
+        timeout: timeout("soon"),
+    })
+    .unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        vec![Error::InvalidTimeout("soon".to_string())]
+    );
+    println!("Recovered the timeout that could be parsed leniently");
+}
@@ -0,0 +1,45 @@
+//! `order` changes where a field's errors appear, and `additional_errors(position = "last")`
+//! puts the errors that belong to no single field after the others, so a report lists the
+//! most important problems first.
+
+use staging::Staging;
+
+#[derive(Debug, Staging)]
+#[staging(error = String, additional_errors(position = "last"))]
+struct Order {
+    note: String,
+    #[staging(order = -1)]
+    payment: String,
+    address: String,
+}
+
+fn main() {
+    let checker = OrderStaging {
+        note: Err("note is too long".to_string()),
+        payment: Err("card was declined".to_string()),
+        address: Err("address is incomplete".to_string()),
+        additional_errors: vec!["the cart is empty".to_string()],
+    };
+    assert_eq!(
+        checker.into_result().unwrap_err(),
+        [
+            "card was declined",
+            "note is too long",
+            "address is incomplete",
+            "the cart is empty",
+        ]
+    );
+
+    let order = Order::try_from(OrderStaging {
+        note: Ok(String::new()),
+        payment: Ok("visa".to_string()),
+        address: Ok("1 Rust Way".to_string()),
+        additional_errors: vec![],
+    })
+    .unwrap();
+    assert_eq!(
+        (order.note, order.payment, order.address),
+        (String::new(), "visa".to_string(), "1 Rust Way".to_string())
+    );
+    println!("Reported the payment error first and the cart error last");
+}
@@ -0,0 +1,36 @@
+//! `patch` emits a struct of `Option`s and an `apply` method on the target that overwrites
+//! each field set in the patch.
+
+use staging::Staging;
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, patch)]
+struct Settings {
+    theme: String,
+    font_size: u8,
+    #[staging(skip)]
+    revision: u32,
+}
+
+fn main() {
+    let mut settings = Settings {
+        theme: "light".to_string(),
+        font_size: 12,
+        revision: 7,
+    };
+
+    settings.apply(SettingsPatch {
+        font_size: Some(14),
+        ..SettingsPatch::default()
+    });
+
+    assert_eq!(
+        settings,
+        Settings {
+            theme: "light".to_string(),
+            font_size: 14,
+            revision: 7,
+        }
+    );
+    println!("Changed only the patched font size");
+}
@@ -0,0 +1,85 @@
+//! `post_validate` checks invariants that involve the whole target once it has been built.
+//! With `multiple`, the check can report several errors at once.
+
+use std::fmt;
+
+use staging::{Errors, Staging};
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    EndsBeforeStart { start: u32, end: u32 },
+    TooManyGuests { guests: u32, rooms: u32 },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(field) => write!(f, "`{}` is required", field),
+            Error::EndsBeforeStart { start, end } => {
+                write!(f, "ends on day {} before it starts on day {}", end, start)
+            }
+            Error::TooManyGuests { guests, rooms } => {
+                write!(f, "{} guests do not fit in {} rooms", guests, rooms)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(
+    error = Error,
+    final_error = Errors::<Error>,
+    builder = Error::Missing,
+    post_validate(path = check_booking, multiple)
+)]
+struct Booking {
+    start: u32,
+    end: u32,
+    guests: u32,
+    rooms: u32,
+}
+
+/// Check the fields against each other, reporting every problem.
+fn check_booking(booking: &Booking) -> Result<(), Vec<Error>> {
+    let mut errors = vec![];
+    if booking.end < booking.start {
+        errors.push(Error::EndsBeforeStart {
+            start: booking.start,
+            end: booking.end,
+        });
+    }
+    if booking.guests > booking.rooms * 2 {
+        errors.push(Error::TooManyGuests {
+            guests: booking.guests,
+            rooms: booking.rooms,
+        });
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn book(start: u32, end: u32, guests: u32, rooms: u32) {
+    let mut staging = BookingStaging::new();
+    staging
+        .set_start(start)
+        .set_end(end)
+        .set_guests(guests)
+        .set_rooms(rooms);
+    match Booking::try_from(staging) {
+        Ok(booking) => println!(
+            "Booked {} rooms for {} guests, days {} to {}",
+            booking.rooms, booking.guests, booking.start, booking.end
+        ),
+        Err(errors) => println!("Invalid booking:\n{}", errors),
+    }
+}
+
+fn main() {
+    book(3, 5, 4, 2);
+    book(5, 3, 4, 2);
+    book(5, 3, 9, 2);
+}
@@ -0,0 +1,80 @@
+//! `pre_finalize` runs a check on the checker before its fields are finalized, for rules
+//! that involve several fields. Its errors are reported alongside those of the fields.
+
+use std::fmt;
+
+use staging::{Errors, Staging};
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    InvalidWeight(String),
+    ExpressNeedsPhone,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(field) => write!(f, "`{}` is required", field),
+            Error::InvalidWeight(value) => write!(f, "`{}` is not a weight in grams", value),
+            Error::ExpressNeedsPhone => write!(f, "express delivery needs a phone number"),
+        }
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(
+    error = Error,
+    final_error = Errors::<Error>,
+    builder = Error::Missing,
+    pre_finalize = check_express
+)]
+struct Shipment {
+    address: String,
+    weight_grams: u32,
+    express: bool,
+    #[staging(optional)]
+    phone: Option<String>,
+}
+
+/// Express delivery needs a phone number. This only looks at `express` once it has been
+/// staged, so it does not repeat the error for a missing field.
+fn check_express(staging: &ShipmentStaging) -> Option<Error> {
+    match (&staging.express, &staging.phone) {
+        (Ok(true), None) => Some(Error::ExpressNeedsPhone),
+        _ => None,
+    }
+}
+
+fn ship(address: &str, weight: &str, express: bool, phone: Option<&str>) {
+    let mut staging = ShipmentStaging::new();
+    staging
+        .set_address(address.to_string())
+        .set_express(express);
+    staging.weight_grams = weight
+        .parse()
+        .map_err(|_| Error::InvalidWeight(weight.to_string()));
+    if let Some(phone) = phone {
+        staging.set_phone(phone.to_string());
+    }
+
+    match Shipment::try_from(staging) {
+        Ok(shipment) => println!(
+            "Shipping {}g to {}{}{}",
+            shipment.weight_grams,
+            shipment.address,
+            if shipment.express { ", express" } else { "" },
+            shipment
+                .phone
+                .map(|phone| format!(", call {}", phone))
+                .unwrap_or_default()
+        ),
+        Err(errors) => println!("Cannot ship to {}:\n{}", address, errors),
+    }
+}
+
+fn main() {
+    ship("1 Main St", "1200", false, None);
+    ship("2 High St", "800", true, Some("555-0100"));
+    ship("3 Low Rd", "heavy", true, None);
+}
@@ -0,0 +1,73 @@
+//! `profile(...)` generates one checker per profile, here to load the same settings from
+//! the command line and from an API request, each with its own error type. Only the
+//! `primary` profile implements `Staged` for the type. Options outside the profiles apply
+//! to both, unless a profile sets them itself.
+
+use staging::{Errors, Staged, Staging};
+
+#[derive(Debug, PartialEq)]
+enum CliError {
+    Missing(&'static str),
+    InvalidPort(String),
+}
+
+#[derive(Debug, PartialEq)]
+enum ApiError {
+    Missing(&'static str),
+}
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(
+    error = CliError,
+    final_error = Errors::<CliError>,
+    builder = CliError::Missing,
+    profile(name = CliStaging, primary),
+    profile(
+        name = ApiStaging,
+        error = ApiError,
+        final_error = Errors::<ApiError>,
+        builder = ApiError::Missing
+    )
+)]
+struct Settings {
+    host: String,
+    port: u16,
+}
+
+/// Finalize any staged type through its `Staged` impl.
+fn load<T: Staged>(staging: T::Staging) -> Result<T, T::Error> {
+    T::finalize(staging)
+}
+
+fn main() {
+    let mut cli = CliStaging::new();
+    cli.set_host("localhost".to_string()).try_set_port(
+        "80a"
+            .parse()
+            .map_err(|_| CliError::InvalidPort("80a".to_string())),
+    );
+    let errors = load::<Settings>(cli).unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        vec![CliError::InvalidPort("80a".to_string())]
+    );
+
+    let mut api = ApiStaging::new();
+    api.set_host("example.com".to_string());
+    let errors = Settings::try_from(api).unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        vec![ApiError::Missing("port")]
+    );
+
+    let mut api = ApiStaging::new();
+    api.set_host("example.com".to_string()).set_port(443);
+    assert_eq!(
+        Settings::try_from(api).unwrap(),
+        Settings {
+            host: "example.com".to_string(),
+            port: 443,
+        }
+    );
+    println!("Loaded settings from both profiles");
+}
@@ -0,0 +1,44 @@
+//! `remote` stages a type from another crate, which cannot derive `Staging` itself, through
+//! a local mirror with the same fields. Here the type is the standard library's `Range`.
+
+use std::ops::Range;
+
+use staging::{Errors, Staging};
+
+#[derive(Staging)]
+#[staging(
+    error = String,
+    final_error = Errors::<String>,
+    name = RangeStaging,
+    remote = Range
+)]
+#[allow(dead_code)]
+struct RangeDef<Idx> {
+    start: Idx,
+    end: Idx,
+}
+
+fn bound(value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("`{}` is not a bound", value))
+}
+
+fn main() {
+    let range = Range::try_from(RangeStaging {
+        start: bound("3"),
+        end: bound("7"),
+    });
+    assert_eq!(range.unwrap(), 3..7);
+
+    let errors = Range::try_from(RangeStaging {
+        start: bound("three"),
+        end: bound("-7"),
+    })
+    .unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        ["`three` is not a bound", "`-7` is not a bound"]
+    );
+    println!("Built a standard library type from its checker");
+}
@@ -0,0 +1,30 @@
+//! `rename` gives a field another name in the checker, here a raw identifier and a name
+//! that would otherwise clash with a generated method, while the target keeps its own.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, final_error = Errors::<String>, inspect)]
+struct Record {
+    #[staging(rename = "r#type")]
+    kind: String,
+    #[staging(rename = "error_total")]
+    errors: u32,
+}
+
+fn main() {
+    let checker = RecordStaging {
+        r#type: Ok("invoice".to_string()),
+        error_total: Ok(0),
+    };
+    assert_eq!(RecordStaging::FIELDS, ["kind", "errors"]);
+    assert!(!checker.has_errors());
+    assert_eq!(
+        Record::try_from(checker).unwrap(),
+        Record {
+            kind: "invoice".to_string(),
+            errors: 0,
+        }
+    );
+    println!("Finalized the renamed fields into the original ones");
+}
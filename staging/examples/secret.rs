@@ -0,0 +1,28 @@
+//! `secret` keeps a field's value out of the checker's `Debug` output, so a checker of
+//! credentials can be logged.
+
+use staging::{Errors, Staging};
+
+#[derive(Staging)]
+#[staging(error = String, final_error = Errors::<String>, derive(Debug))]
+struct Credentials {
+    user: String,
+    #[staging(secret)]
+    password: String,
+}
+
+fn main() {
+    let checker = CredentialsStaging {
+        user: Ok("ferris".to_string()),
+        password: Ok("hunter2".to_string()),
+    };
+    let logged = format!("{:?}", checker);
+    assert!(logged.contains("ferris"));
+    assert!(logged.contains("<redacted>"));
+    assert!(!logged.contains("hunter2"));
+    println!("{}", logged);
+
+    let credentials = Credentials::try_from(checker).unwrap();
+    assert_eq!(credentials.user, "ferris");
+    assert_eq!(credentials.password, "hunter2");
+}
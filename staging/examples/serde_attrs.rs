@@ -0,0 +1,43 @@
+//! `serde(...)` puts serde attributes on the checker and its fields, for a checker that
+//! derives `Serialize` or `Deserialize`, e.g. to save a half-finished form.
+
+use serde::{Deserialize, Serialize};
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(
+    error = String,
+    final_error = Errors::<String>,
+    derive(Debug, PartialEq, Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+struct Contact {
+    first_name: String,
+    #[staging(serde(rename = "mail"))]
+    email_address: String,
+}
+
+fn main() {
+    let checker = ContactStaging {
+        first_name: Ok("Ferris".to_string()),
+        email_address: Err("`ferris@` is not an email address".to_string()),
+    };
+    let saved = serde_json::to_string(&checker).unwrap();
+    assert_eq!(
+        saved,
+        r#"{"firstName":{"Ok":"Ferris"},"mail":{"Err":"`ferris@` is not an email address"}}"#
+    );
+
+    let mut restored: ContactStaging = serde_json::from_str(&saved).unwrap();
+    assert_eq!(restored, checker);
+
+    restored.email_address = Ok("ferris@example.com".to_string());
+    assert_eq!(
+        Contact::try_from(restored).unwrap(),
+        Contact {
+            first_name: "Ferris".to_string(),
+            email_address: "ferris@example.com".to_string(),
+        }
+    );
+    println!("Saved and restored the checker with its serde attributes");
+}
@@ -0,0 +1,65 @@
+//! `setters` lets a checker be filled in over several phases of a pipeline, with each
+//! phase setting the fields it knows about instead of building a struct literal.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    Missing(&'static str),
+    InvalidPort(String),
+}
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(
+    error = Error,
+    final_error = Errors::<Error>,
+    missing = Error::Missing("value"),
+    setters
+)]
+struct Endpoint {
+    #[staging(into)]
+    host: String,
+    port: u16,
+    #[staging(optional)]
+    path: Option<String>,
+}
+
+/// The first phase reads the host from the environment.
+fn from_environment(checker: &mut EndpointStaging) {
+    checker.set_host("example.com");
+}
+
+/// The second phase parses the port, which may fail.
+fn from_arguments(checker: &mut EndpointStaging, port: &str) {
+    checker.try_set_port(
+        port.parse()
+            .map_err(|_| Error::InvalidPort(port.to_string())),
+    );
+}
+
+fn main() {
+    let mut checker = EndpointStaging::default();
+    from_environment(&mut checker);
+    from_arguments(&mut checker, "8080");
+    checker.set_path("/health".to_string());
+    assert_eq!(
+        Endpoint::try_from(checker).unwrap(),
+        Endpoint {
+            host: "example.com".to_string(),
+            port: 8080,
+            path: Some("/health".to_string()),
+        }
+    );
+
+    let mut checker = EndpointStaging::default();
+    from_arguments(&mut checker, "eighty");
+    let errors = Endpoint::try_from(checker).unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        vec![
+            Error::Missing("value"),
+            Error::InvalidPort("eighty".to_string())
+        ]
+    );
+    println!("Filled in the checker over two phases");
+}
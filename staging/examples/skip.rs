@@ -0,0 +1,35 @@
+//! `skip` leaves a field out of the checker, filling it with `Default::default()` or the
+//! given expression when the checker is finalized.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, final_error = Errors::<String>)]
+struct Document {
+    title: String,
+    body: String,
+    #[staging(skip)]
+    views: u64,
+    #[staging(skip = String::from("draft"))]
+    status: String,
+}
+
+fn main() {
+    // The checker only has the fields that are checked.
+    let checker = DocumentStaging {
+        title: Ok("Release notes".to_string()),
+        body: Ok("Everything is faster.".to_string()),
+    };
+    assert_eq!(DocumentStaging::FIELDS, ["title", "body"]);
+
+    assert_eq!(
+        Document::try_from(checker).unwrap(),
+        Document {
+            title: "Release notes".to_string(),
+            body: "Everything is faster.".to_string(),
+            views: 0,
+            status: "draft".to_string(),
+        }
+    );
+    println!("Filled in the skipped fields");
+}
@@ -0,0 +1,93 @@
+use std::{fmt, num::ParseIntError, ops::Range};
+
+use staging::{Spanned, Staging};
+
+#[derive(Debug)]
+enum FieldError {
+    Empty,
+    InvalidNumber(ParseIntError),
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldError::Empty => write!(f, "value must not be empty"),
+            FieldError::InvalidNumber(err) => write!(f, "invalid number: {}", err),
+        }
+    }
+}
+
+/// Every error carries the byte range of the input it refers to.
+#[derive(Debug)]
+struct Error {
+    message: String,
+    span: Range<usize>,
+}
+
+impl From<Spanned<FieldError>> for Error {
+    fn from(err: Spanned<FieldError>) -> Self {
+        Error {
+            message: err.value.to_string(),
+            span: err.span,
+        }
+    }
+}
+
+struct Errors(Vec<Error>);
+
+impl FromIterator<Error> for Errors {
+    fn from_iter<T: IntoIterator<Item = Error>>(iter: T) -> Self {
+        Errors(iter.into_iter().collect())
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(error = Error, final_error = Errors)]
+struct Entry {
+    #[staging(spanned, error = FieldError)]
+    key: Spanned<String>,
+    #[staging(spanned, error = FieldError)]
+    value: Spanned<u32>,
+}
+
+/// Split `input` at the first `=`, recording where each part came from.
+fn stage(input: &str) -> EntryStaging {
+    let (key, value) = input.split_once('=').unwrap_or((input, ""));
+    let value_start = key.len() + 1;
+
+    EntryStaging {
+        key: Spanned::new(
+            match key {
+                "" => Err(FieldError::Empty),
+                key => Ok(key.to_string()),
+            },
+            0..key.len(),
+        ),
+        value: Spanned::new(
+            value.parse().map_err(FieldError::InvalidNumber),
+            value_start..value_start + value.len(),
+        ),
+    }
+}
+
+fn main() {
+    for input in ["retries=3", "=3", "timeout=soon", "=x"] {
+        match Entry::try_from(stage(input)) {
+            Ok(entry) => println!(
+                "Parsed `{}` (at {:?}) = {} (at {:?})",
+                *entry.key, entry.key.span, *entry.value, entry.value.span
+            ),
+            Err(Errors(errors)) => {
+                println!("Failed to parse '{}':", input);
+                for err in errors {
+                    println!(
+                        "    {} at {:?}: {}",
+                        &input[err.span.clone()],
+                        err.span,
+                        err.message
+                    );
+                }
+            }
+        }
+    }
+}
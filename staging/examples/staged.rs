@@ -0,0 +1,52 @@
+//! `#[staged]` generates the same checker as `#[derive(Staging)]`, but also rewrites the
+//! annotated struct, removing the `staging` attributes from it and its fields.
+
+use std::fmt;
+
+use staging::{Errors, staged};
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    TooLong(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(field) => write!(f, "`{}` is required", field),
+            Error::TooLong(len) => write!(f, "{} characters is too long", len),
+        }
+    }
+}
+
+fn short(value: &str) -> Result<(), Error> {
+    if value.len() <= 16 {
+        Ok(())
+    } else {
+        Err(Error::TooLong(value.len()))
+    }
+}
+
+#[staged(error = Error, final_error = Errors::<Error>, builder = Error::Missing)]
+#[derive(Debug, Clone, PartialEq)]
+struct Tag {
+    #[staging(into, validate = short)]
+    name: String,
+    #[staging(optional, into)]
+    color: Option<String>,
+}
+
+fn main() {
+    let mut staging = TagStaging::new();
+    staging.set_name("urgent").set_color("red");
+    let tag = staging.build().expect("tag is valid");
+    println!("{:?} == {:?}: {}", tag, tag.clone(), tag == tag.clone());
+
+    let mut staging = TagStaging::new();
+    staging.set_name("much too long to be a tag");
+    match staging.build() {
+        Ok(tag) => println!("{:?}", tag),
+        Err(errors) => println!("Invalid tag: {}", errors),
+    }
+}
@@ -0,0 +1,78 @@
+//! The derive implements `Staged` for each type, linking it to its checker, so one generic
+//! function can finalize and report on any staged type.
+
+use std::fmt;
+
+use staging::{Errors, Staged, Staging};
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    TooOld(u32),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(field) => write!(f, "`{}` is required", field),
+            Error::TooOld(age) => write!(f, "{} is too old", age),
+        }
+    }
+}
+
+fn plausible_age(age: &u32) -> Result<(), Error> {
+    if *age <= 150 {
+        Ok(())
+    } else {
+        Err(Error::TooOld(*age))
+    }
+}
+
+#[derive(Staging)]
+#[staging(error = Error, final_error = Errors::<Error>, builder = Error::Missing)]
+struct User {
+    name: String,
+    #[staging(validate = plausible_age)]
+    age: u32,
+}
+
+#[derive(Staging)]
+#[staging(error = Error, final_error = Errors::<Error>, builder = Error::Missing)]
+struct Team {
+    title: String,
+    size: usize,
+}
+
+/// Finalize any staged type, printing why it could not be built.
+fn load<T: Staged>(staging: T::Staging) -> Option<T>
+where
+    T::Error: fmt::Display,
+{
+    match T::finalize(staging) {
+        Ok(value) => Some(value),
+        Err(errors) => {
+            println!("Could not load:\n{}", errors);
+            None
+        }
+    }
+}
+
+fn main() {
+    let mut user = UserStaging::new();
+    user.set_name("Ada".to_string()).set_age(36);
+    if let Some(user) = load::<User>(user) {
+        println!("{} is {}", user.name, user.age);
+    }
+
+    let mut user = UserStaging::new();
+    user.set_age(200);
+    if let Some(user) = load::<User>(user) {
+        println!("{} is {}", user.name, user.age);
+    }
+
+    let mut team = TeamStaging::new();
+    team.set_title("Compilers".to_string()).set_size(4);
+    if let Some(team) = load::<Team>(team) {
+        println!("{} has {} members", team.title, team.size);
+    }
+}
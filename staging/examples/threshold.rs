@@ -0,0 +1,89 @@
+//! `threshold` lets a checker finalize despite additional errors that rank below it,
+//! returning them as warnings from `finalize_with_warnings` instead.
+
+use staging::{Errors, Severity, Staging};
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+enum Level {
+    Minor,
+    Major,
+}
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    UnusedKey(&'static str),
+    PortInUse(u16),
+}
+
+impl Severity for Error {
+    type Level = Level;
+
+    fn severity(&self) -> Level {
+        match self {
+            Error::UnusedKey(_) => Level::Minor,
+            Error::PortInUse(_) => Level::Major,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Warning(String);
+
+impl From<Error> for Warning {
+    fn from(error: Error) -> Self {
+        Warning(format!("{:?}", error))
+    }
+}
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(
+    error = Error,
+    final_error = Errors::<Error>,
+    additional_errors,
+    warnings = Warning,
+    threshold = Level::Major,
+    inspect
+)]
+struct Server {
+    port: u16,
+}
+
+fn main() {
+    let mut server = ServerStaging {
+        port: Ok(8080),
+        additional_errors: Vec::new(),
+        warnings: Vec::new(),
+    };
+    server.additional_errors.push(Error::UnusedKey("colour"));
+    server.warn(Warning("no TLS".to_string()));
+
+    // The minor error neither counts as an error nor stops finalizing.
+    assert!(!server.has_errors());
+    assert!(server.is_complete());
+    assert_eq!(server.error_count(), 1);
+    let (finished, warnings) = server.finalize_with_warnings().unwrap();
+    assert_eq!(finished, Server { port: 8080 });
+    assert_eq!(
+        warnings,
+        vec![
+            Warning("no TLS".to_string()),
+            Warning("UnusedKey(\"colour\")".to_string()),
+        ]
+    );
+
+    let mut server = ServerStaging {
+        port: Ok(80),
+        additional_errors: Vec::new(),
+        warnings: Vec::new(),
+    };
+    server.additional_errors.push(Error::PortInUse(80));
+    server.additional_errors.push(Error::UnusedKey("colour"));
+    assert!(server.has_errors());
+    assert!(!server.is_complete());
+    let errors = server.finalize_with_warnings().unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        vec![Error::PortInUse(80)]
+    );
+    println!("Finalized with warnings below the threshold");
+}
@@ -0,0 +1,92 @@
+//! `try_all!`, `collect_errors!` and the `zip` functions collect every error from a set
+//! of results, as a checker does for its fields, without defining a struct to stage
+//! them in.
+
+use std::{fmt, net::IpAddr, num::ParseIntError};
+
+use staging::{Errors, collect_errors, try_all, zip2};
+
+#[derive(Debug)]
+enum Error {
+    Missing(&'static str),
+    InvalidAddress(String),
+    InvalidPort(String),
+    Port(ParseIntError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing(part) => write!(f, "missing {}", part),
+            Error::InvalidAddress(value) => write!(f, "`{}` is not an IP address", value),
+            Error::InvalidPort(value) => write!(f, "`{}` is not a port", value),
+            Error::Port(err) => write!(f, "invalid port: {}", err),
+        }
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Self {
+        Error::Port(err)
+    }
+}
+
+fn part<'a>(parts: &[&'a str], index: usize, name: &'static str) -> Result<&'a str, Error> {
+    parts.get(index).copied().ok_or(Error::Missing(name))
+}
+
+/// Parse `address port protocol`, reporting every part that is wrong.
+fn parse_listener(line: &str) -> Result<(IpAddr, u16, String), Errors<Error>> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let (address, port, protocol) = try_all!(
+        part(&parts, 0, "address").and_then(|address| address
+            .parse::<IpAddr>()
+            .map_err(|_| Error::InvalidAddress(address.to_string()))),
+        part(&parts, 1, "port").and_then(|port| port
+            .parse::<u16>()
+            .map_err(|_| Error::InvalidPort(port.to_string()))),
+        part(&parts, 2, "protocol").map(str::to_string),
+    )
+    .map_err(Errors::from)?;
+
+    Ok((address, port, protocol))
+}
+
+/// Parse a `name:port` backend, converting each error to `Error`.
+fn parse_backend(backend: &str) -> Result<(String, u16), Errors<Error>> {
+    let (name, port) = backend.split_once(':').unwrap_or((backend, ""));
+    let name = Some(name.to_string())
+        .filter(|name| !name.is_empty())
+        .ok_or(Error::Missing("name"));
+    collect_errors!(Errors<Error>; name, port.parse::<u16>())
+}
+
+/// Parse a `first-last` range of ports.
+fn parse_port_range(range: &str) -> Result<(u16, u16), Vec<Error>> {
+    let (first, last) = range.split_once('-').unwrap_or((range, ""));
+    let port = |port: &str| {
+        port.parse::<u16>()
+            .map_err(|_| Error::InvalidPort(port.to_string()))
+    };
+    zip2(port(first), port(last))
+}
+
+fn main() {
+    for line in ["127.0.0.1 8080 http", "localhost 80000 http", "::1"] {
+        match parse_listener(line) {
+            Ok((address, port, protocol)) => println!("{} on {}:{}", protocol, address, port),
+            Err(errors) => println!("Invalid listener `{}`:\n{}", line, errors),
+        }
+    }
+
+    for backend in ["web:8080", ":http"] {
+        match parse_backend(backend) {
+            Ok((name, port)) => println!("{} on port {}", name, port),
+            Err(errors) => println!("Invalid backend `{}`:\n{}", backend, errors),
+        }
+    }
+
+    for range in ["8000-8080", "http-https"] {
+        println!("{}: {:?}", range, parse_port_range(range));
+    }
+}
@@ -0,0 +1,31 @@
+//! `try_finalize` returns the checker unchanged when it still holds errors, so the values
+//! that were already valid are kept while the rest are fixed.
+
+use staging::Staging;
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, derive(Debug), try_finalize)]
+struct Address {
+    street: String,
+    city: String,
+}
+
+fn main() {
+    let checker = AddressStaging {
+        street: Ok("1 Rust Way".to_string()),
+        city: Err("city is required".to_string()),
+    };
+
+    let mut checker = checker.try_finalize().unwrap_err();
+    assert_eq!(checker.street, Ok("1 Rust Way".to_string()));
+
+    checker.city = Ok("Crabtown".to_string());
+    assert_eq!(
+        checker.try_finalize().unwrap(),
+        Address {
+            street: "1 Rust Way".to_string(),
+            city: "Crabtown".to_string(),
+        }
+    );
+    println!("Kept the street while the city was fixed");
+}
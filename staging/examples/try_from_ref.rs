@@ -0,0 +1,37 @@
+//! `try_from_ref` finalizes a clone of the checker, so a form can be checked after every
+//! change while it is still being filled in.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(
+    error = String,
+    final_error = Errors::<String>,
+    missing = "required".to_string(),
+    derive(Clone),
+    try_from_ref
+)]
+struct Signup {
+    email: String,
+    age: u8,
+}
+
+fn main() {
+    let mut form = SignupStaging::new();
+    form.email = Ok("ferris@example.com".to_string());
+
+    // The form is only borrowed, so it can still be changed after a failed attempt.
+    let errors = Signup::try_from(&form).unwrap_err();
+    assert_eq!(errors.into_iter().collect::<Vec<_>>(), ["required"]);
+
+    form.age = Ok(16);
+    assert_eq!(
+        Signup::try_from(&form).unwrap(),
+        Signup {
+            email: "ferris@example.com".to_string(),
+            age: 16,
+        }
+    );
+    assert_eq!(form.age, Ok(16));
+    println!("Checked the form twice without consuming it");
+}
@@ -0,0 +1,75 @@
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+use staging::Staging;
+
+#[derive(Debug)]
+enum Error {
+    InvalidCoordinate(ParseIntError),
+    OutOfBounds,
+    Multiple(Vec<Error>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidCoordinate(err) => write!(f, "invalid coordinate: {}", err),
+            Error::OutOfBounds => write!(f, "coordinate out of bounds"),
+            Error::Multiple(errors) => {
+                for (i, err) in errors.iter().enumerate() {
+                    writeln!(f, "{}: {}", i + 1, err)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromIterator<Error> for Error {
+    fn from_iter<T: IntoIterator<Item = Error>>(iter: T) -> Self {
+        let errors: Vec<Error> = iter.into_iter().collect();
+        if errors.len() == 1 {
+            errors.into_iter().next().unwrap()
+        } else {
+            Error::Multiple(errors)
+        }
+    }
+}
+
+#[derive(Debug, Staging)]
+#[staging(error = Error, derive(Debug))]
+struct Point(i32, i32);
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+fn coordinate(s: &str) -> Result<i32, Error> {
+    let value = s.trim().parse::<i32>().map_err(Error::InvalidCoordinate)?;
+    if value.abs() > 1000 {
+        Err(Error::OutOfBounds)
+    } else {
+        Ok(value)
+    }
+}
+
+impl FromStr for Point {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.split_once(',').unwrap_or((s, ""));
+        Point::try_from(PointStaging(coordinate(x), coordinate(y)))
+    }
+}
+
+fn main() {
+    let lines = ["1,2", "1,two", "one,2000", "-5,5000"];
+
+    for line in &lines {
+        match line.parse::<Point>() {
+            Ok(point) => println!("Parsed point: {}", point),
+            Err(err) => println!("Failed to parse '{}': {:?}", line, err),
+        }
+    }
+}
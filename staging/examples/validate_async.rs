@@ -0,0 +1,66 @@
+//! `validate_async` awaits a check for a field, such as a lookup in a database, and
+//! `try_finalize_async` reports its errors alongside all the others.
+
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, final_error = Errors::<String>)]
+struct Registration {
+    #[staging(validate_async = name_is_free)]
+    user_name: String,
+    age: u8,
+}
+
+/// Stands in for a query against the table of existing users.
+async fn name_is_free(name: &String) -> Result<(), String> {
+    match ["ferris", "corro"].contains(&name.as_str()) {
+        true => Err(format!("`{}` is already taken", name)),
+        false => Ok(()),
+    }
+}
+
+/// Run a future that never waits on anything to completion.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+fn main() {
+    let errors = block_on(
+        RegistrationStaging {
+            user_name: Ok("ferris".to_string()),
+            age: Err("age must be a number".to_string()),
+        }
+        .try_finalize_async(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        ["`ferris` is already taken", "age must be a number"]
+    );
+
+    let registration = block_on(
+        RegistrationStaging {
+            user_name: Ok("crabby".to_string()),
+            age: Ok(16),
+        }
+        .try_finalize_async(),
+    );
+    assert_eq!(
+        registration.unwrap(),
+        Registration {
+            user_name: "crabby".to_string(),
+            age: 16,
+        }
+    );
+    println!("Checked the user name asynchronously");
+}
@@ -0,0 +1,44 @@
+//! `vis` sets the visibility of the checker apart from the deriving type's, so a module can
+//! export the target while keeping its checker internal.
+
+mod api {
+    use staging::{Errors, Staging};
+
+    #[derive(Debug, PartialEq, Staging)]
+    #[staging(error = String, final_error = Errors::<String>, vis = "pub(self)")]
+    pub struct Order {
+        pub item: String,
+        pub quantity: u32,
+    }
+
+    /// Parse an order from `item x quantity`. The checker is not visible outside `api`,
+    /// so `Order` does not implement `Staged`.
+    pub fn parse_order(input: &str) -> Result<Order, Errors<String>> {
+        let (item, quantity) = input.split_once(" x ").unwrap_or((input, ""));
+        Order::try_from(OrderStaging {
+            item: Ok(item.trim().to_string()),
+            quantity: quantity
+                .trim()
+                .parse()
+                .map_err(|_| format!("`{}` is not a quantity", quantity.trim())),
+        })
+    }
+}
+
+fn main() {
+    assert_eq!(
+        api::parse_order("tea x 2").unwrap(),
+        api::Order {
+            item: "tea".to_string(),
+            quantity: 2,
+        }
+    );
+    assert_eq!(
+        api::parse_order("cake")
+            .unwrap_err()
+            .into_iter()
+            .collect::<Vec<_>>(),
+        ["`` is not a quantity"]
+    );
+    println!("Parsed orders without exposing the checker");
+}
@@ -0,0 +1,49 @@
+//! `warnings` adds a list of non-fatal issues to the checker, which
+//! `finalize_with_warnings` returns along with the target.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = String, final_error = Errors::<String>, warnings = String)]
+struct Upload {
+    file_name: String,
+    size: u64,
+}
+
+fn check_upload(file_name: &str, size: u64) -> UploadStaging {
+    let mut checker = UploadStaging {
+        file_name: Ok(file_name.to_string()),
+        size: Ok(size),
+        warnings: Vec::new(),
+    };
+    if !file_name.contains('.') {
+        checker.warn(format!("`{}` has no extension", file_name));
+    }
+    if size > 1_000_000 {
+        checker.warn("large uploads may be slow");
+    }
+    checker
+}
+
+fn main() {
+    let (upload, warnings) = check_upload("README", 2_000_000)
+        .finalize_with_warnings()
+        .unwrap();
+    assert_eq!(
+        upload,
+        Upload {
+            file_name: "README".to_string(),
+            size: 2_000_000,
+        }
+    );
+    assert_eq!(
+        warnings,
+        ["`README` has no extension", "large uploads may be slow"]
+    );
+
+    let (_, warnings) = check_upload("notes.txt", 10)
+        .finalize_with_warnings()
+        .unwrap();
+    assert!(warnings.is_empty());
+    println!("Kept the warnings through finalization");
+}
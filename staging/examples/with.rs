@@ -0,0 +1,68 @@
+//! `with` stores a field in the checker as another type and converts it with user code
+//! during finalization, here keeping a color as the text it was written as. The field
+//! holds a `Result` of the staged type, so an error can still be recorded before then.
+
+use staging::{Errors, Staging};
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    InvalidColor(String),
+}
+
+#[derive(Debug, PartialEq)]
+struct Color(u8, u8, u8);
+
+/// Colors are staged as the text of a `#rrggbb` code and parsed when finalizing.
+mod hex_color {
+    use super::{Color, Error};
+
+    pub type Staged = String;
+
+    pub fn finalize(staged: Staged) -> Result<Color, Error> {
+        let invalid = || Error::InvalidColor(staged.clone());
+        let digits = staged.strip_prefix('#').ok_or_else(invalid)?;
+        if digits.len() != 6 {
+            return Err(invalid());
+        }
+        let channel =
+            |at: usize| u8::from_str_radix(&digits[at..at + 2], 16).map_err(|_| invalid());
+        Ok(Color(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+#[derive(Debug, PartialEq, Staging)]
+#[staging(error = Error, final_error = Errors::<Error>)]
+struct Theme {
+    #[staging(with = hex_color)]
+    background: Color,
+    #[staging(with = hex_color)]
+    foreground: Color,
+}
+
+fn main() {
+    let theme = Theme::try_from(ThemeStaging {
+        background: Ok("#102030".to_string()),
+        foreground: Ok("#ffffff".to_string()),
+    });
+    assert_eq!(
+        theme.unwrap(),
+        Theme {
+            background: Color(0x10, 0x20, 0x30),
+            foreground: Color(0xff, 0xff, 0xff),
+        }
+    );
+
+    let errors = Theme::try_from(ThemeStaging {
+        background: Ok("blue".to_string()),
+        foreground: Ok("#fff".to_string()),
+    })
+    .unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        vec![
+            Error::InvalidColor("blue".to_string()),
+            Error::InvalidColor("#fff".to_string()),
+        ]
+    );
+    println!("Converted the staged colors when finalizing");
+}
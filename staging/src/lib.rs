@@ -1,4 +1,139 @@
 pub use staging_macro::*;
 
+#[cfg(feature = "serde")]
+pub use staging_core::DeserializeError;
+pub use staging_core::{
+    Accumulator, AtLeastOne, ElementError, Errors, Field, FieldError, FromStaging, IntoFinal,
+    Severity, Spanned, Staged,
+};
+pub use staging_core::{try_collect_all, zip2, zip3, zip4, zip5, zip6};
+
 #[doc(hidden)]
 pub use staging_core::export;
+
+/// Create a checker from the fields that are known, leaving every other field as the
+/// checker's `Default` sets it.
+///
+/// ```
+/// use staging::{Staging, stage};
+///
+/// #[derive(Debug, Staging)]
+/// #[staging(error = String, final_error = Vec::<String>, missing = "missing".to_string())]
+/// struct Args {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// let name = "Ada".to_string();
+/// let staging = stage! { ArgsStaging { name: Ok(name) } };
+/// assert_eq!(staging.age, Err("missing".to_string()));
+/// assert_eq!(Args::try_from(staging).unwrap_err(), ["missing"]);
+/// ```
+///
+/// Each field is assigned in the order written, as `field: value` or as `field` for a
+/// local of the same name. Tuple fields are written by index, as in `0: Ok(name)`. The
+/// checker must implement `Default`, as it does when `builder` or `missing` is set, so
+/// unmentioned fields hold their missing error and `additional_errors` starts empty.
+/// Unlike a struct expression, this also works for checkers that are `non_exhaustive`.
+#[macro_export]
+macro_rules! stage {
+    ($checker:path { $($field:tt $(: $value:expr)?),* $(,)? }) => {{
+        let mut checker: $checker = $crate::export::Default::default();
+        $(checker.$field = $crate::stage!(@value $field $($value)?);)*
+        checker
+    }};
+    (@value $field:ident) => {
+        $field
+    };
+    (@value $field:tt $value:expr) => {
+        $value
+    };
+}
+
+/// Evaluate each `Result` and return all of their values as a tuple, or every error
+/// if any of them failed.
+///
+/// ```
+/// use staging::try_all;
+///
+/// let host: Result<&str, String> = Ok("localhost");
+/// let port = "http".parse::<u16>().map_err(|err| err.to_string());
+/// let user = "".parse::<u32>().map_err(|err| err.to_string());
+/// assert_eq!(try_all!(host, port, user).unwrap_err().len(), 2);
+///
+/// let (host, port) = try_all!(Ok::<_, String>("localhost"), Ok(8080))?;
+/// assert_eq!((host, port), ("localhost", 8080));
+/// # Ok::<(), Vec<String>>(())
+/// ```
+///
+/// This finalizes a set of results as a checker would, for free functions that do not
+/// need a struct. Every expression is evaluated, in order, and the errors are returned
+/// as a `Vec` in that order. The results must share an error type.
+#[macro_export]
+macro_rules! try_all {
+    ($($result:expr),+ $(,)?) => {{
+        let mut errors = $crate::export::Vec::new();
+        $crate::try_all!(@take errors [] $($result,)+)
+    }};
+    // Each expansion binds its own `value`, so the bindings gathered in `[...]` stay
+    // distinct despite sharing a name.
+    (@take $errors:ident [$($value:ident)*] $result:expr, $($rest:expr,)*) => {{
+        let value = match $result {
+            $crate::export::Ok(value) => $crate::export::Some(value),
+            $crate::export::Err(err) => {
+                $errors.push(err);
+                $crate::export::None
+            }
+        };
+        $crate::try_all!(@take $errors [$($value)* value] $($rest,)*)
+    }};
+    (@take $errors:ident [$($value:ident)*]) => {
+        match ($($value,)*) {
+            ($($crate::export::Some($value),)*) => $crate::export::Ok(($($value,)*)),
+            _ => $crate::export::Err($errors),
+        }
+    };
+}
+
+/// Evaluate each `Result` and return all of their values as a tuple, or every error
+/// collected into the aggregate error type `$aggregate` if any of them failed.
+///
+/// ```
+/// use staging::{Errors, collect_errors};
+///
+/// #[derive(Debug)]
+/// enum Error {
+///     Port(std::num::ParseIntError),
+///     Host(String),
+/// }
+///
+/// impl From<std::num::ParseIntError> for Error {
+///     fn from(err: std::num::ParseIntError) -> Self {
+///         Error::Port(err)
+///     }
+/// }
+///
+/// impl From<String> for Error {
+///     fn from(err: String) -> Self {
+///         Error::Host(err)
+///     }
+/// }
+///
+/// let host: Result<&str, String> = Err("no host".to_string());
+/// let errors = collect_errors!(Errors<Error>; host, "http".parse::<u16>()).unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// ```
+///
+/// Unlike `try_all!`, the results may have different error types. Each error is
+/// converted with `Into` to the type that `$aggregate` collects with `FromIterator`, such
+/// as `E` for `Errors<E>` or a `final_error` type, which must have a single
+/// `FromIterator` impl so that type can be inferred.
+#[macro_export]
+macro_rules! collect_errors {
+    ($aggregate:ty; $($result:expr),+ $(,)?) => {
+        $crate::export::Result::map_err(
+            $crate::try_all!($($crate::export::Result::map_err($result, $crate::export::Into::into)),+),
+            |errors| <$aggregate as $crate::export::FromIterator<_>>::from_iter(errors),
+        )
+    };
+}
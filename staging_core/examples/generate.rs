@@ -0,0 +1,26 @@
+//! Generate a checker outside of a procedural macro, as a build script or code generator
+//! would, and print the code that the derive would have emitted.
+
+use staging_core::{StagingOptions, generate_for};
+use syn::parse_quote;
+
+fn main() {
+    let input: syn::DeriveInput = parse_quote! {
+        #[staging(final_error = Vec::<String>)]
+        pub struct Config {
+            /// Address to listen on
+            pub listen: String,
+            #[staging(optional)]
+            pub workers: Option<u16>,
+        }
+    };
+
+    let options = StagingOptions {
+        crate_root: Some(parse_quote!(::staging)),
+        error: Some(parse_quote!(String)),
+        setters: true,
+        ..StagingOptions::default()
+    };
+
+    println!("{}", generate_for(&input, &options));
+}
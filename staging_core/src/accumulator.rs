@@ -0,0 +1,92 @@
+use std::{slice, vec};
+
+/// Collects errors from hand-written code, such as a `FromStr` impl that fills in a
+/// checker, so it can keep going after the first error.
+///
+/// Finishing returns every error as a `Vec`, the same as a checker's `into_result`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[must_use = "the errors are only reported once the accumulator is finished"]
+pub struct Accumulator<E> {
+    errors: Vec<E>,
+}
+
+impl<E> Accumulator<E> {
+    pub fn new() -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    /// Return the value of `result`, or record its error and return `None`.
+    pub fn handle<T>(&mut self, result: Result<T, impl Into<E>>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.errors.push(err.into());
+                None
+            }
+        }
+    }
+
+    pub fn push(&mut self, error: impl Into<E>) {
+        self.errors.push(error.into());
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, E> {
+        self.errors.iter()
+    }
+
+    /// Build the value with `f` if no errors were recorded, or return them all.
+    pub fn finish_with<T>(self, f: impl FnOnce() -> T) -> Result<T, Vec<E>> {
+        if self.errors.is_empty() {
+            Ok(f())
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    /// Return every error that was recorded, if there are any.
+    pub fn finish(self) -> Result<(), Vec<E>> {
+        self.finish_with(|| ())
+    }
+
+    pub fn into_vec(self) -> Vec<E> {
+        self.errors
+    }
+}
+
+impl<E> Default for Accumulator<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Extend<E> for Accumulator<E> {
+    fn extend<T: IntoIterator<Item = E>>(&mut self, iter: T) {
+        self.errors.extend(iter);
+    }
+}
+
+impl<E> IntoIterator for Accumulator<E> {
+    type Item = E;
+    type IntoIter = vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a Accumulator<E> {
+    type Item = &'a E;
+    type IntoIter = slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
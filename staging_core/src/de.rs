@@ -0,0 +1,638 @@
+//! Support for checkers that implement `serde::Deserialize` with `deserialize`.
+//!
+//! Each field's value is read into a buffered `Content` before it is deserialized into the
+//! field's type, so a value of the wrong shape becomes an error on that field while the
+//! rest of the document is still read. Only a document that is malformed as a whole, such
+//! as invalid JSON, fails deserialization.
+
+use std::{error::Error, fmt, marker::PhantomData};
+
+use serde::de::{
+    self, Deserialize, DeserializeSeed, Deserializer, EnumAccess, IgnoredAny, IntoDeserializer,
+    MapAccess, SeqAccess, VariantAccess, Visitor,
+    value::{MapDeserializer, SeqDeserializer},
+};
+
+/// A checker field whose value could not be deserialized into the field's type.
+///
+/// Checkers with `deserialize` convert this into each field's error type with `Into`, so
+/// the error type must implement `From<DeserializeError>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeserializeError {
+    field: &'static str,
+    message: String,
+}
+
+impl DeserializeError {
+    /// Name of the field whose value was invalid.
+    pub fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// The message from the deserializer, such as `invalid type: string "x", expected u32`.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {}", self.field, self.message)
+    }
+}
+
+impl Error for DeserializeError {}
+
+/// The fields of a checker with `deserialize`, as read from a map by `deserialize_checker`.
+#[doc(hidden)]
+pub trait DeserializeFields<'de>: Sized {
+    /// Name of the checker, for error messages.
+    const NAME: &'static str;
+    /// Key of every field, for error messages.
+    const FIELDS: &'static [&'static str];
+    /// Whether a key that names no field fails deserialization.
+    const DENY_UNKNOWN_FIELDS: bool;
+
+    /// Read the value for `key` into its field, returning the field's key, or `None` if
+    /// `key` names no field and its value has not been read.
+    fn deserialize_field<A: MapAccess<'de>>(
+        &mut self,
+        key: &str,
+        map: &mut A,
+    ) -> Result<Option<&'static str>, A::Error>;
+}
+
+/// Deserialize a map into `checker`, whose fields start as they are before staging.
+#[doc(hidden)]
+pub fn deserialize_checker<'de, D, S>(deserializer: D, checker: S) -> Result<S, D::Error>
+where
+    D: Deserializer<'de>,
+    S: DeserializeFields<'de>,
+{
+    deserializer.deserialize_struct(S::NAME, S::FIELDS, CheckerVisitor(checker))
+}
+
+struct CheckerVisitor<S>(S);
+
+impl<'de, S: DeserializeFields<'de>> Visitor<'de> for CheckerVisitor<S> {
+    type Value = S;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "struct {}", S::NAME)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<S, A::Error> {
+        let mut seen = Vec::new();
+        while let Some(key) = map.next_key::<String>()? {
+            match self.0.deserialize_field(&key, &mut map)? {
+                Some(field) if seen.contains(&field) => {
+                    return Err(de::Error::duplicate_field(field));
+                }
+                Some(field) => seen.push(field),
+                None if S::DENY_UNKNOWN_FIELDS => {
+                    return Err(de::Error::unknown_field(&key, S::FIELDS));
+                }
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(self.0)
+    }
+}
+
+/// Read the next value from `map` and deserialize it as a `T`, returning an error for
+/// `field` if it is not a valid `T`.
+#[doc(hidden)]
+pub fn deserialize_value<'de, T, A>(
+    map: &mut A,
+    field: &'static str,
+) -> Result<Result<T, DeserializeError>, A::Error>
+where
+    T: Deserialize<'de>,
+    A: MapAccess<'de>,
+{
+    let content = map.next_value::<Content>()?;
+    Ok(
+        T::deserialize(ContentDeserializer::<de::value::Error>::new(content)).map_err(|err| {
+            DeserializeError {
+                field,
+                message: err.to_string(),
+            }
+        }),
+    )
+}
+
+/// A value read from the document, kept so it can be deserialized again.
+enum Content {
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    I128(i128),
+    F64(f64),
+    Char(char),
+    String(String),
+    Bytes(Vec<u8>),
+    None,
+    Some(Box<Content>),
+    Unit,
+    Newtype(Box<Content>),
+    Seq(Vec<Content>),
+    Map(Vec<(Content, Content)>),
+}
+
+impl<'de> Deserialize<'de> for Content {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+struct ContentVisitor;
+
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Content, E> {
+        Ok(Content::Bool(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Content, E> {
+        Ok(Content::I64(value))
+    }
+
+    fn visit_i128<E>(self, value: i128) -> Result<Content, E> {
+        Ok(Content::I128(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Content, E> {
+        Ok(Content::U64(value))
+    }
+
+    fn visit_u128<E>(self, value: u128) -> Result<Content, E> {
+        Ok(Content::U128(value))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Content, E> {
+        Ok(Content::F64(value))
+    }
+
+    fn visit_char<E>(self, value: char) -> Result<Content, E> {
+        Ok(Content::Char(value))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Content, E> {
+        Ok(Content::String(value.to_owned()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Content, E> {
+        Ok(Content::String(value))
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Content, E> {
+        Ok(Content::Bytes(value.to_owned()))
+    }
+
+    fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Content, E> {
+        Ok(Content::Bytes(value))
+    }
+
+    fn visit_none<E>(self) -> Result<Content, E> {
+        Ok(Content::None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Content, D::Error> {
+        Content::deserialize(deserializer).map(|content| Content::Some(Box::new(content)))
+    }
+
+    fn visit_unit<E>(self) -> Result<Content, E> {
+        Ok(Content::Unit)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Content, D::Error> {
+        Content::deserialize(deserializer).map(|content| Content::Newtype(Box::new(content)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Content, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Content::Seq(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Content, A::Error> {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Content::Map(entries))
+    }
+}
+
+/// Deserializes a buffered `Content`, reporting errors as `E`.
+struct ContentDeserializer<E> {
+    content: Content,
+    error: PhantomData<E>,
+}
+
+impl<E> ContentDeserializer<E> {
+    fn new(content: Content) -> Self {
+        Self {
+            content,
+            error: PhantomData,
+        }
+    }
+}
+
+impl<'de, E: de::Error> IntoDeserializer<'de, E> for Content {
+    type Deserializer = ContentDeserializer<E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        ContentDeserializer::new(self)
+    }
+}
+
+impl<'de, E: de::Error> Deserializer<'de> for ContentDeserializer<E> {
+    type Error = E;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::Bool(value) => visitor.visit_bool(value),
+            Content::U64(value) => visitor.visit_u64(value),
+            Content::I64(value) => visitor.visit_i64(value),
+            Content::U128(value) => visitor.visit_u128(value),
+            Content::I128(value) => visitor.visit_i128(value),
+            Content::F64(value) => visitor.visit_f64(value),
+            Content::Char(value) => visitor.visit_char(value),
+            Content::String(value) => visitor.visit_string(value),
+            Content::Bytes(value) => visitor.visit_byte_buf(value),
+            Content::None => visitor.visit_none(),
+            Content::Some(content) => visitor.visit_some(ContentDeserializer::new(*content)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Newtype(content) => {
+                visitor.visit_newtype_struct(ContentDeserializer::new(*content))
+            }
+            Content::Seq(items) => {
+                let mut seq = SeqDeserializer::new(items.into_iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Content::Map(entries) => {
+                let mut map = MapDeserializer::new(entries.into_iter());
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(content) => visitor.visit_some(ContentDeserializer::new(*content)),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.content {
+            Content::Newtype(content) => {
+                visitor.visit_newtype_struct(ContentDeserializer::new(*content))
+            }
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        let (variant, value) = match self.content {
+            Content::String(variant) => (Content::String(variant), None),
+            Content::Map(entries) if entries.len() == 1 => {
+                let (variant, value) = entries.into_iter().next().expect("map has one entry");
+                (variant, Some(value))
+            }
+            _ => {
+                return Err(de::Error::invalid_type(
+                    de::Unexpected::Other("non-enum value"),
+                    &"a string or a map with a single key",
+                ));
+            }
+        };
+        visitor.visit_enum(EnumDeserializer {
+            variant,
+            value,
+            error: PhantomData,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// An externally tagged enum variant, read from a string or a map with a single key.
+struct EnumDeserializer<E> {
+    variant: Content,
+    value: Option<Content>,
+    error: PhantomData<E>,
+}
+
+impl<'de, E: de::Error> EnumAccess<'de> for EnumDeserializer<E> {
+    type Error = E;
+    type Variant = VariantDeserializer<E>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), E> {
+        let variant = seed.deserialize(ContentDeserializer::new(self.variant))?;
+        Ok((
+            variant,
+            VariantDeserializer {
+                value: self.value,
+                error: PhantomData,
+            },
+        ))
+    }
+}
+
+struct VariantDeserializer<E> {
+    value: Option<Content>,
+    error: PhantomData<E>,
+}
+
+impl<E: de::Error> VariantDeserializer<E> {
+    /// The variant's value, for variants that hold one.
+    fn content(self, expected: &'static str) -> Result<ContentDeserializer<E>, E> {
+        match self.value {
+            Some(content) => Ok(ContentDeserializer::new(content)),
+            None => Err(de::Error::invalid_type(
+                de::Unexpected::UnitVariant,
+                &expected,
+            )),
+        }
+    }
+}
+
+impl<'de, E: de::Error> VariantAccess<'de> for VariantDeserializer<E> {
+    type Error = E;
+
+    fn unit_variant(self) -> Result<(), E> {
+        match self.value {
+            None | Some(Content::Unit) => Ok(()),
+            Some(content) => Deserialize::deserialize(ContentDeserializer::<E>::new(content)),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, E> {
+        seed.deserialize(self.content("newtype variant")?)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, E> {
+        self.content("tuple variant")?.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        self.content("struct variant")?.deserialize_any(visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Missing(&'static str),
+        Invalid(DeserializeError),
+    }
+
+    impl From<DeserializeError> for Error {
+        fn from(err: DeserializeError) -> Self {
+            Error::Invalid(err)
+        }
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Level {
+        Low,
+        Custom(u8),
+        Range { min: u8, max: u8 },
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Meters(f64);
+
+    /// A checker as `deserialize` generates it, with each field starting as its missing
+    /// error.
+    #[derive(Debug)]
+    struct Checker {
+        name: Result<String, Error>,
+        port: Result<u16, Error>,
+        nickname: Result<Option<String>, Error>,
+        level: Result<Level, Error>,
+        height: Result<Meters, Error>,
+    }
+
+    impl Checker {
+        fn new() -> Self {
+            Self {
+                name: Err(Error::Missing("name")),
+                port: Err(Error::Missing("port")),
+                nickname: Err(Error::Missing("nickname")),
+                level: Err(Error::Missing("level")),
+                height: Err(Error::Missing("height")),
+            }
+        }
+    }
+
+    impl<'de> DeserializeFields<'de> for Checker {
+        const NAME: &'static str = "Checker";
+        const FIELDS: &'static [&'static str] = &["name", "port", "nickname", "level", "height"];
+        const DENY_UNKNOWN_FIELDS: bool = false;
+
+        fn deserialize_field<A: MapAccess<'de>>(
+            &mut self,
+            key: &str,
+            map: &mut A,
+        ) -> Result<Option<&'static str>, A::Error> {
+            match key {
+                "name" => self.name = deserialize_value(map, "name")?.map_err(Into::into),
+                "port" => self.port = deserialize_value(map, "port")?.map_err(Into::into),
+                "nickname" => {
+                    self.nickname = deserialize_value(map, "nickname")?.map_err(Into::into)
+                }
+                "level" => self.level = deserialize_value(map, "level")?.map_err(Into::into),
+                "height" => self.height = deserialize_value(map, "height")?.map_err(Into::into),
+                _ => return Ok(None),
+            }
+            Ok(Self::FIELDS.iter().copied().find(|field| *field == key))
+        }
+    }
+
+    /// `Checker` with `serde(deny_unknown_fields)`.
+    struct Strict(Checker);
+
+    impl<'de> DeserializeFields<'de> for Strict {
+        const NAME: &'static str = "Strict";
+        const FIELDS: &'static [&'static str] = Checker::FIELDS;
+        const DENY_UNKNOWN_FIELDS: bool = true;
+
+        fn deserialize_field<A: MapAccess<'de>>(
+            &mut self,
+            key: &str,
+            map: &mut A,
+        ) -> Result<Option<&'static str>, A::Error> {
+            self.0.deserialize_field(key, map)
+        }
+    }
+
+    fn read(json: &str) -> Result<Checker, serde_json::Error> {
+        deserialize_checker(
+            &mut serde_json::Deserializer::from_str(json),
+            Checker::new(),
+        )
+    }
+
+    /// The message of the error that `field` holds.
+    fn invalid<T: fmt::Debug>(field: Result<T, Error>) -> String {
+        match field {
+            Err(Error::Invalid(err)) => err.message().to_string(),
+            other => panic!("expected a deserialization error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn valid_document() {
+        let checker =
+            read(r#"{"name": "web", "port": 80, "nickname": "w", "level": "Low", "height": 1.5}"#)
+                .unwrap();
+        assert_eq!(checker.name, Ok("web".to_string()));
+        assert_eq!(checker.port, Ok(80));
+        assert_eq!(checker.nickname, Ok(Some("w".to_string())));
+        assert_eq!(checker.level, Ok(Level::Low));
+        assert_eq!(checker.height, Ok(Meters(1.5)));
+    }
+
+    #[test]
+    fn wrong_type_is_field_error() {
+        let checker = read(r#"{"port": "http", "name": "web", "level": 3}"#).unwrap();
+        match &checker.port {
+            Err(Error::Invalid(err)) => {
+                assert_eq!(err.field(), "port");
+                assert!(err.message().contains("invalid type"), "{}", err);
+            }
+            other => panic!("expected a deserialization error, found {:?}", other),
+        }
+        assert!(invalid(checker.level).contains("string or a map"));
+        // The rest of the document is still read.
+        assert_eq!(checker.name, Ok("web".to_string()));
+
+        let checker = read(r#"{"port": 70000}"#).unwrap();
+        assert!(invalid(checker.port).contains("70000"));
+    }
+
+    #[test]
+    fn missing_field_keeps_missing_error() {
+        let checker = read(r#"{"name": "web"}"#).unwrap();
+        assert_eq!(checker.name, Ok("web".to_string()));
+        assert_eq!(checker.port, Err(Error::Missing("port")));
+        assert_eq!(checker.height, Err(Error::Missing("height")));
+    }
+
+    #[test]
+    fn duplicate_key_fails() {
+        let err = read(r#"{"name": "web", "name": "api"}"#).unwrap_err();
+        assert!(
+            err.to_string().contains("duplicate field `name`"),
+            "{}",
+            err
+        );
+
+        // A duplicate is rejected even if its first value was invalid.
+        let err = read(r#"{"port": "x", "port": 80}"#).unwrap_err();
+        assert!(
+            err.to_string().contains("duplicate field `port`"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn unknown_keys() {
+        let checker = read(r#"{"extra": {"nested": [1, 2]}, "name": "web"}"#).unwrap();
+        assert_eq!(checker.name, Ok("web".to_string()));
+
+        let err = deserialize_checker(
+            &mut serde_json::Deserializer::from_str(r#"{"name": "web", "extra": 1}"#),
+            Strict(Checker::new()),
+        )
+        .map(|_| ())
+        .unwrap_err();
+        assert!(err.to_string().contains("unknown field `extra`"), "{}", err);
+    }
+
+    #[test]
+    fn malformed_document_fails() {
+        assert!(read(r#"{"name": "web""#).is_err());
+        assert!(read(r#"["web", 80]"#).is_err());
+    }
+
+    #[test]
+    fn option_fields() {
+        let checker = read(r#"{"nickname": null}"#).unwrap();
+        assert_eq!(checker.nickname, Ok(None));
+
+        let checker = read(r#"{"nickname": 5}"#).unwrap();
+        assert!(invalid(checker.nickname).contains("invalid type"));
+    }
+
+    #[test]
+    fn enum_fields() {
+        let checker = read(r#"{"level": {"Custom": 3}}"#).unwrap();
+        assert_eq!(checker.level, Ok(Level::Custom(3)));
+
+        let checker = read(r#"{"level": {"Range": {"min": 1, "max": 9}}}"#).unwrap();
+        assert_eq!(checker.level, Ok(Level::Range { min: 1, max: 9 }));
+
+        let checker = read(r#"{"level": "Extreme"}"#).unwrap();
+        assert!(invalid(checker.level).contains("unknown variant `Extreme`"));
+
+        let checker = read(r#"{"level": "Custom"}"#).unwrap();
+        assert!(invalid(checker.level).contains("unit variant"));
+    }
+
+    #[test]
+    fn newtype_fields() {
+        let checker = read(r#"{"height": 2}"#).unwrap();
+        assert_eq!(checker.height, Ok(Meters(2.0)));
+
+        let checker = read(r#"{"height": "tall"}"#).unwrap();
+        assert!(invalid(checker.height).contains("invalid type"));
+    }
+}
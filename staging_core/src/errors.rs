@@ -0,0 +1,352 @@
+use std::{fmt, iter, slice, vec};
+
+/// Every error found while finalizing a checker.
+///
+/// This implements `FromIterator<E>`, so it can be used directly as a checker's
+/// `final_error` without writing a combined error type by hand.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Errors<E>(Vec<E>);
+
+impl<E> Errors<E> {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, E> {
+        self.0.iter()
+    }
+
+    pub fn as_slice(&self) -> &[E] {
+        &self.0
+    }
+
+    pub fn into_vec(self) -> Vec<E> {
+        self.0
+    }
+
+    /// Return the only error, or `self` if there are none or several.
+    pub fn into_single(mut self) -> Result<E, Self> {
+        if self.0.len() == 1 {
+            Ok(self.0.remove(0))
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<E> Default for Errors<E> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<E> From<Vec<E>> for Errors<E> {
+    fn from(errors: Vec<E>) -> Self {
+        Self(errors)
+    }
+}
+
+impl<E> From<Errors<E>> for Vec<E> {
+    fn from(errors: Errors<E>) -> Self {
+        errors.0
+    }
+}
+
+impl<E> FromIterator<E> for Errors<E> {
+    fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<E> Extend<E> for Errors<E> {
+    fn extend<T: IntoIterator<Item = E>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl<E> IntoIterator for Errors<E> {
+    type Item = E;
+    type IntoIter = vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a Errors<E> {
+    type Item = &'a E;
+    type IntoIter = slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// A single error is shown on its own; several are shown as a numbered list, one per line.
+impl<E: fmt::Display> fmt::Display for Errors<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let [error] = self.0.as_slice() {
+            return error.fmt(f);
+        }
+
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {}", i + 1, error)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for Errors<E> {
+    /// The source of the only error, if there is exactly one.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self.0.as_slice() {
+            [error] => error.source(),
+            _ => None,
+        }
+    }
+}
+
+/// A list of errors that is known to contain at least one error.
+///
+/// Use `#[staging(non_empty)]` to build a checker's `final_error` from this type, so
+/// code handling a failed finalization never has to consider an empty list.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AtLeastOne<E> {
+    first: E,
+    rest: Vec<E>,
+}
+
+impl<E> AtLeastOne<E> {
+    pub fn new(first: E) -> Self {
+        Self {
+            first,
+            rest: Vec::new(),
+        }
+    }
+
+    pub fn first(&self) -> &E {
+        &self.first
+    }
+
+    /// Keep only the first error, dropping the rest.
+    pub fn into_first(self) -> E {
+        self.first
+    }
+
+    pub fn len(&self) -> usize {
+        1 + self.rest.len()
+    }
+
+    /// Always `false`; provided for symmetry with `len`.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn push(&mut self, error: E) {
+        self.rest.push(error);
+    }
+
+    pub fn iter(&self) -> iter::Chain<iter::Once<&E>, slice::Iter<'_, E>> {
+        iter::once(&self.first).chain(self.rest.iter())
+    }
+
+    /// Remove each error that `same` finds equal to an earlier one, wherever it is in
+    /// the list. The first error is always kept.
+    pub fn dedup_by(&mut self, mut same: impl FnMut(&E, &E) -> bool) {
+        let mut index = 0;
+        while index < self.rest.len() {
+            let (earlier, later) = self.rest.split_at(index);
+            let error = &later[0];
+            if same(&self.first, error) || earlier.iter().any(|earlier| same(earlier, error)) {
+                self.rest.remove(index);
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    /// Convert each error, keeping their order.
+    pub fn map<F>(self, mut f: impl FnMut(E) -> F) -> AtLeastOne<F> {
+        AtLeastOne {
+            first: f(self.first),
+            rest: self.rest.into_iter().map(f).collect(),
+        }
+    }
+
+    pub fn into_vec(self) -> Vec<E> {
+        let mut errors = Vec::with_capacity(self.len());
+        errors.push(self.first);
+        errors.extend(self.rest);
+        errors
+    }
+}
+
+/// Fails with the original `Vec` if it is empty.
+impl<E> TryFrom<Vec<E>> for AtLeastOne<E> {
+    type Error = Vec<E>;
+
+    fn try_from(errors: Vec<E>) -> Result<Self, Self::Error> {
+        let mut errors = errors.into_iter();
+        match errors.next() {
+            Some(first) => Ok(Self {
+                first,
+                rest: errors.collect(),
+            }),
+            None => Err(Vec::new()),
+        }
+    }
+}
+
+impl<E> From<AtLeastOne<E>> for Vec<E> {
+    fn from(errors: AtLeastOne<E>) -> Self {
+        errors.into_vec()
+    }
+}
+
+impl<E> From<AtLeastOne<E>> for Errors<E> {
+    fn from(errors: AtLeastOne<E>) -> Self {
+        Errors(errors.into_vec())
+    }
+}
+
+impl<E> Extend<E> for AtLeastOne<E> {
+    fn extend<T: IntoIterator<Item = E>>(&mut self, iter: T) {
+        self.rest.extend(iter);
+    }
+}
+
+impl<E> IntoIterator for AtLeastOne<E> {
+    type Item = E;
+    type IntoIter = iter::Chain<iter::Once<E>, vec::IntoIter<E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self.first).chain(self.rest)
+    }
+}
+
+impl<'a, E> IntoIterator for &'a AtLeastOne<E> {
+    type Item = &'a E;
+    type IntoIter = iter::Chain<iter::Once<&'a E>, slice::Iter<'a, E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Shown the same way as [`Errors`].
+impl<E: fmt::Display> fmt::Display for AtLeastOne<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.rest.is_empty() {
+            return self.first.fmt(f);
+        }
+
+        for (i, error) in self.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {}", i + 1, error)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for AtLeastOne<E> {
+    /// The source of the only error, if there is exactly one.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        if self.rest.is_empty() {
+            self.first.source()
+        } else {
+            None
+        }
+    }
+}
+
+/// An error along with the name of the field it came from.
+///
+/// Use `#[staging(field_errors)]` to have a checker wrap each error it collects in this type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldError<E> {
+    /// Name of the field on the target type; tuple fields use their index.
+    pub field: &'static str,
+    pub source: E,
+}
+
+impl<E> FieldError<E> {
+    pub fn new(field: &'static str, source: E) -> Self {
+        Self { field, source }
+    }
+
+    /// Convert the wrapped error, keeping the field name.
+    pub fn map<F>(self, f: impl FnOnce(E) -> F) -> FieldError<F> {
+        FieldError {
+            field: self.field,
+            source: f(self.source),
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for FieldError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {}", self.field, self.source)
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for FieldError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// An error along with the index of the collection element it came from.
+///
+/// A bare `#[staging(elements)]` field wraps each element error in this type and converts it
+/// with `Into` to the checker's error type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ElementError<E> {
+    /// Position of the element in the field's collection.
+    pub index: usize,
+    pub source: E,
+}
+
+impl<E> ElementError<E> {
+    pub fn new(index: usize, source: E) -> Self {
+        Self { index, source }
+    }
+
+    /// Convert the wrapped error, keeping the index.
+    pub fn map<F>(self, f: impl FnOnce(E) -> F) -> ElementError<F> {
+        ElementError {
+            index: self.index,
+            source: f(self.source),
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ElementError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}]: {}", self.index, self.source)
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ElementError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// An error that can be ranked by how serious it is.
+///
+/// Use `#[staging(threshold = level)]` to let a checker finalize despite additional errors
+/// that rank below `level`, passing them on as warnings.
+pub trait Severity {
+    type Level: PartialOrd;
+
+    fn severity(&self) -> Self::Level;
+}
@@ -0,0 +1,100 @@
+use crate::{Errors, FieldError};
+
+/// The value staged for a field, or the error found while staging it.
+///
+/// This wraps the `Result` stored in a checker field with combinators for building it up
+/// from raw input, e.g. `Field::from(parse(s)).require(|v| *v > 0, |_| Error::Zero).into()`.
+/// It converts to and from `Result` with `From`, so it can be assigned to a checker field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[must_use]
+pub struct Field<T, E>(Result<T, E>);
+
+impl<T, E> Field<T, E> {
+    pub fn ok(value: T) -> Self {
+        Self(Ok(value))
+    }
+
+    pub fn err(error: E) -> Self {
+        Self(Err(error))
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.0.is_ok()
+    }
+
+    pub fn is_err(&self) -> bool {
+        self.0.is_err()
+    }
+
+    pub fn as_result(&self) -> Result<&T, &E> {
+        self.0.as_ref()
+    }
+
+    pub fn into_result(self) -> Result<T, E> {
+        self.0
+    }
+
+    /// Transform the value, keeping any error.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Field<U, E> {
+        Field(self.0.map(f))
+    }
+
+    /// Transform the error, keeping any value.
+    pub fn map_err<F>(self, f: impl FnOnce(E) -> F) -> Field<T, F> {
+        Field(self.0.map_err(f))
+    }
+
+    /// Run a fallible step on the value, converting its error with `Into`.
+    pub fn and_then<U, F: Into<E>>(self, f: impl FnOnce(T) -> Result<U, F>) -> Field<U, E> {
+        Field(self.0.and_then(|value| f(value).map_err(Into::into)))
+    }
+
+    /// Run a step that can report several errors on the value, such as one built with
+    /// `try_all!` or `zip2`, keeping all of them.
+    pub fn and_then_collect<U>(
+        self,
+        f: impl FnOnce(T) -> Result<U, Vec<E>>,
+    ) -> Field<U, Errors<E>> {
+        match self.0 {
+            Ok(value) => Field(f(value).map_err(Errors::from)),
+            Err(err) => Field(Err(Errors::from(vec![err]))),
+        }
+    }
+
+    /// Fail with `error` if the value does not pass `check`.
+    pub fn require(self, check: impl FnOnce(&T) -> bool, error: impl FnOnce(&T) -> E) -> Self {
+        match self.0 {
+            Ok(value) if !check(&value) => Self(Err(error(&value))),
+            result => Self(result),
+        }
+    }
+
+    /// Label the error with the name of the field it came from.
+    pub fn with_context(self, field: &'static str) -> Field<T, FieldError<E>> {
+        self.map_err(|err| FieldError::new(field, err))
+    }
+}
+
+impl<T, E> From<Result<T, E>> for Field<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        Self(result)
+    }
+}
+
+impl<T, E> From<Field<T, E>> for Result<T, E> {
+    fn from(field: Field<T, E>) -> Self {
+        field.0
+    }
+}
+
+impl<T, E> AsRef<Result<T, E>> for Field<T, E> {
+    fn as_ref(&self) -> &Result<T, E> {
+        &self.0
+    }
+}
+
+impl<T, E> AsMut<Result<T, E>> for Field<T, E> {
+    fn as_mut(&mut self) -> &mut Result<T, E> {
+        &mut self.0
+    }
+}
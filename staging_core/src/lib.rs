@@ -1,283 +1,7337 @@
 use std::borrow::Cow;
 
 use darling::{
-    FromDeriveInput, FromField,
-    ast::Data,
-    util::{Flag, PathList},
+    FromDeriveInput, FromField, FromMeta, FromVariant,
+    ast::{Data, Fields, Style},
+    util::{Flag, Override, PathList, SpannedValue},
 };
 use proc_macro2::TokenStream;
-use quote::{ToTokens, TokenStreamExt, quote};
-use syn::{Ident, Path, parse_quote, parse_quote_spanned, spanned::Spanned};
+use quote::{ToTokens, TokenStreamExt, format_ident, quote};
+use syn::{
+    Ident, Member, Path, ext::IdentExt, parse::Parser as _, parse_quote, parse_quote_spanned,
+    punctuated::Punctuated, spanned::Spanned as _,
+};
+
+mod accumulator;
+#[cfg(feature = "serde")]
+mod de;
+mod errors;
+mod field;
+mod spanned;
+mod staged;
+mod zip;
+
+pub use accumulator::Accumulator;
+#[cfg(feature = "serde")]
+pub use de::DeserializeError;
+pub use errors::{AtLeastOne, ElementError, Errors, FieldError, Severity};
+pub use field::Field;
+pub use spanned::Spanned;
+pub use staged::{FromStaging, IntoFinal, Staged};
+pub use zip::{try_collect_all, zip2, zip3, zip4, zip5, zip6};
 
 pub fn derive_staging(input: TokenStream) -> TokenStream {
-    match try_derive_staging(input, None) {
+    derive_staging_with_options(input, &StagingOptions::default())
+}
+
+pub fn derive_staging_with_crate_root(input: TokenStream, crate_root: Option<Path>) -> TokenStream {
+    derive_staging_with_options(
+        input,
+        &StagingOptions {
+            crate_root,
+            ..StagingOptions::default()
+        },
+    )
+}
+
+/// Derive with a naming convention for checkers, e.g. from a macro that wraps this one
+/// and wants the same convention across a whole crate. The `name`, `prefix` and `suffix`
+/// options on the deriving type take precedence.
+pub fn derive_staging_with_naming(
+    input: TokenStream,
+    crate_root: Option<Path>,
+    naming: Naming,
+) -> TokenStream {
+    derive_staging_with_options(
+        input,
+        &StagingOptions {
+            crate_root,
+            naming,
+            ..StagingOptions::default()
+        },
+    )
+}
+
+/// Derive with options set by the caller, e.g. from a macro that wraps this one and
+/// configures the checker from its own attributes. The options on the deriving type
+/// take precedence.
+pub fn derive_staging_with_options(input: TokenStream, options: &StagingOptions) -> TokenStream {
+    match try_derive_staging(input, options) {
         Ok(tokens) => tokens,
         Err(err) => err.write_errors(),
     }
 }
 
-pub fn derive_staging_with_crate_root(input: TokenStream, crate_root: Option<Path>) -> TokenStream {
-    match try_derive_staging(input, crate_root) {
+/// Generate the checker for an item that has already been parsed, such as one built by a
+/// code generator or read by a build script that writes the output to a file.
+///
+/// This does not need to run inside a procedural macro. The item's `#[staging(...)]`
+/// attributes are read as they are by the derive, and invalid options are reported as
+/// `compile_error!` invocations in the output.
+pub fn generate_for(input: &syn::DeriveInput, options: &StagingOptions) -> TokenStream {
+    match try_generate(input, options) {
         Ok(tokens) => tokens,
         Err(err) => err.write_errors(),
     }
 }
 
+/// Expand the `#[staged(...)]` attribute on `item`, which takes the same options as
+/// `#[staging(...)]`.
+///
+/// Unlike the derive, this returns the item itself along with the checker, so the item
+/// can be rewritten: its `staging` attributes are removed, and with `serde_try_from` it
+/// is marked `#[serde(try_from = "Checker")]` so it deserializes through its checker.
+pub fn staged_with_options(
+    args: TokenStream,
+    item: TokenStream,
+    options: &StagingOptions,
+) -> TokenStream {
+    let mut input: syn::DeriveInput = match syn::parse2(item) {
+        Ok(input) => input,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let mut errors = darling::Error::accumulator();
+    let args = errors
+        .handle(
+            Punctuated::<darling::ast::NestedMeta, syn::Token![,]>::parse_terminated
+                .parse2(args)
+                .map_err(darling::Error::from),
+        )
+        .unwrap_or_default();
+    let mut serde_try_from = None;
+    let mut kept = vec![];
+    for arg in args {
+        match arg {
+            darling::ast::NestedMeta::Meta(syn::Meta::Path(path))
+                if path.is_ident("serde_try_from") =>
+            {
+                serde_try_from = Some(path);
+            }
+            arg => kept.push(arg),
+        }
+    }
+    if !kept.is_empty() {
+        input.attrs.push(parse_quote!(#[staging(#(#kept),*)]));
+    }
+
+    let generated = generate_for(&input, options);
+    let serde_attr =
+        serde_try_from.and_then(|path| errors.handle(serde_try_from_attr(&input, options, &path)));
+    strip_staging_attrs(&mut input);
+    input.attrs.extend(serde_attr);
+    let errors = errors.finish().err().map(darling::Error::write_errors);
+
+    quote! {
+        #input
+        #generated
+        #errors
+    }
+}
+
+/// How a checker is named when the deriving type does not set `name`: the type's name
+/// between `prefix` and `suffix`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Naming {
+    pub prefix: String,
+    pub suffix: String,
+}
+
+impl Default for Naming {
+    fn default() -> Self {
+        Self {
+            prefix: String::new(),
+            suffix: "Staging".to_string(),
+        }
+    }
+}
+
+/// Options for every type derived with [`derive_staging_with_options`], matching the
+/// struct options of the same name.
+///
+/// Each is a default for the deriving type: an option set in its `#[staging(...)]`
+/// attributes is used instead, and a flag is set if either sets it. The traits in
+/// `derive` are added to those the deriving type lists.
+#[derive(Debug, Clone, Default)]
+pub struct StagingOptions {
+    pub crate_root: Option<Path>,
+    /// Naming convention used when the deriving type does not set `name`, `prefix` or
+    /// `suffix`
+    pub naming: Naming,
+    pub error: Option<Path>,
+    pub final_error: Option<Path>,
+    pub name: Option<Ident>,
+    pub vis: Option<syn::Visibility>,
+    pub derive: Vec<Path>,
+    pub additional_errors: bool,
+    pub builder: Option<Path>,
+    pub setters: bool,
+    pub accessors: bool,
+    pub inspect: bool,
+    pub fail_fast: bool,
+    pub field_errors: bool,
+}
+
 fn try_derive_staging(
     input: TokenStream,
-    crate_root: Option<Path>,
+    options: &StagingOptions,
 ) -> darling::Result<TokenStream> {
-    let mut receiver = Receiver::from_derive_input(&syn::parse2(input)?)?;
-    if receiver.crate_root.is_none() {
-        receiver.crate_root = crate_root;
-    }
-    let mut tokens = TokenStream::new();
-    receiver.to_tokens(&mut tokens);
-    Ok(tokens)
+    try_generate(&syn::parse2(input)?, options)
 }
 
-#[derive(Debug, Clone, FromField)]
-#[darling(attributes(staging))]
-struct Field {
-    ident: Option<syn::Ident>,
-    ty: syn::Type,
-}
+fn try_generate(
+    input: &syn::DeriveInput,
+    options: &StagingOptions,
+) -> darling::Result<TokenStream> {
+    check_shape(input)?;
+    let (inputs, profiled) = split_profiles(input.clone())?;
+    let several = inputs.len() > 1;
 
-#[derive(Debug, Clone, FromDeriveInput)]
-#[darling(attributes(staging), forward_attrs(doc, cfg), supports(struct_named))]
-struct Receiver {
-    ident: syn::Ident,
-    vis: syn::Visibility,
-    generics: syn::Generics,
-    attrs: Vec<syn::Attribute>,
-    data: Data<(), Field>,
-    /// Traits that the generated struct should derive
-    derive: Option<PathList>,
-    /// Name for the generated checker type
-    name: Option<Ident>,
-    /// Path to the error type
-    error: Path,
-    /// The final error type to return (defaults to `error` if not specified)
-    final_error: Option<Path>,
-    /// Crate root path (defaults to `::staging_core` if not specified)
-    crate_root: Option<Path>,
-    /// If set, the generated struct will have an extra `Vec` to store errors that
-    /// could not be associated with a specific field.
-    additional_errors: Flag,
+    let mut errors = darling::Error::accumulator();
+    let mut names: Vec<Ident> = vec![];
+    let mut primary = false;
+    let mut tokens = TokenStream::new();
+    for input in inputs {
+        let receiver = Receiver::from_derive_input(&input).and_then(|mut receiver| {
+            receiver.apply_options(options);
+            receiver.implements_staged = !several || receiver.primary.is_present();
+            receiver.validate()
+        });
+        let Some(receiver) = errors.handle(receiver) else {
+            continue;
+        };
+        if receiver.primary.is_present() {
+            let message = if !profiled {
+                Some("`primary` can only be set inside `profile(...)`")
+            } else if primary {
+                Some("Only one profile can be `primary`; it is the one that implements `Staged`")
+            } else {
+                None
+            };
+            if let Some(message) = message {
+                errors.push(darling::Error::custom(message).with_span(&receiver.primary.span()));
+                continue;
+            }
+            primary = true;
+        }
+        let name = receiver.checker_name();
+        if names.contains(&name) {
+            errors.push(
+                darling::Error::custom(format!(
+                    "Another profile already generates `{}`; give each profile its own `name`",
+                    name
+                ))
+                .with_span(&input.ident),
+            );
+            continue;
+        }
+
+        names.push(name);
+        if let Some(receiver_tokens) = errors.handle(receiver_tokens(receiver)) {
+            tokens.extend(receiver_tokens);
+        }
+    }
+
+    errors.finish_with(tokens)
 }
 
-impl Receiver {
-    pub fn checker_name(&self) -> Ident {
-        self.name
-            .clone()
-            .unwrap_or_else(|| Ident::new(&format!("{}Staging", self.ident), self.ident.span()))
+/// Options that may be given more than once, which a profile adds to rather than
+/// overrides.
+const REPEATED_OPTIONS: &[&str] = &["serde", "attrs", "at_least_one_of"];
+
+/// Split the `profile(...)` options out of the deriving type's `staging` attributes,
+/// returning one input per profile, which has the options outside any profile along
+/// with its own, and whether there were any profiles. An option set both outside and
+/// inside a profile takes the profile's value. An input without profiles is returned as
+/// it is.
+fn split_profiles(input: syn::DeriveInput) -> darling::Result<(Vec<syn::DeriveInput>, bool)> {
+    type Items = Punctuated<darling::ast::NestedMeta, syn::Token![,]>;
+
+    // Each attribute is kept in place, along with the options of a `staging` attribute
+    // that are outside any profile.
+    let mut attrs: Vec<(&syn::Attribute, Option<Vec<darling::ast::NestedMeta>>)> = vec![];
+    let mut profiles = vec![];
+    for attr in &input.attrs {
+        if !attr.path().is_ident("staging") {
+            attrs.push((attr, None));
+            continue;
+        }
+
+        let mut kept = vec![];
+        for item in attr.parse_args_with(Items::parse_terminated)? {
+            match item {
+                darling::ast::NestedMeta::Meta(syn::Meta::List(list))
+                    if list.path.is_ident("profile") =>
+                {
+                    profiles.push(list.tokens);
+                }
+                item => kept.push(item),
+            }
+        }
+        attrs.push((attr, Some(kept)));
     }
 
-    pub fn final_error(&self) -> &Path {
-        self.final_error.as_ref().unwrap_or(&self.error)
+    let with_options = |overridden: &[Path]| {
+        let mut input = input.clone();
+        input.attrs = attrs
+            .iter()
+            .map(|(attr, items)| match items {
+                None => (*attr).clone(),
+                Some(items) => {
+                    let items = items.iter().filter(|item| match item {
+                        darling::ast::NestedMeta::Meta(meta) => !overridden.contains(meta.path()),
+                        darling::ast::NestedMeta::Lit(_) => true,
+                    });
+                    parse_quote!(#[staging(#(#items),*)])
+                }
+            })
+            .collect();
+        input
+    };
+
+    if profiles.is_empty() {
+        return Ok((vec![with_options(&[])], false));
     }
 
-    pub fn crate_root<'a>(&'a self) -> Cow<'a, Path> {
-        self.crate_root
-            .as_ref()
-            .map(Cow::Borrowed)
-            .unwrap_or_else(|| Cow::Owned(parse_quote!(::staging_core)))
+    let mut inputs = vec![];
+    for profile in profiles {
+        let overridden = syn::parse::Parser::parse2(Items::parse_terminated, profile.clone())?
+            .into_iter()
+            .filter_map(|item| match item {
+                darling::ast::NestedMeta::Meta(meta) => Some(meta.path().clone()),
+                darling::ast::NestedMeta::Lit(_) => None,
+            })
+            .filter(|path| !REPEATED_OPTIONS.iter().any(|option| path.is_ident(option)))
+            .collect::<Vec<_>>();
+        let mut input = with_options(&overridden);
+        input.attrs.push(parse_quote!(#[staging(#profile)]));
+        inputs.push(input);
     }
+    Ok((inputs, true))
+}
 
-    fn additional_errors_ident(&self) -> Option<Ident> {
-        if self.additional_errors.is_present() {
-            Some(Ident::new(
-                "additional_errors",
-                self.additional_errors.span(),
+/// The `#[serde(try_from = "...")]` attribute that deserializes `input` through its
+/// checker, for `#[staged(serde_try_from)]`.
+fn serde_try_from_attr(
+    input: &syn::DeriveInput,
+    options: &StagingOptions,
+    option: &Path,
+) -> darling::Result<syn::Attribute> {
+    let (mut inputs, _) = split_profiles(input.clone())?;
+    if inputs.len() > 1 {
+        return Err(
+            darling::Error::custom("`serde_try_from` cannot be used with `profile`")
+                .with_span(option),
+        );
+    }
+
+    let mut receiver = Receiver::from_derive_input(&inputs.remove(0))?;
+    receiver.apply_options(options);
+    for (present, other) in [
+        (receiver.generic_error.is_some(), "generic_error"),
+        (receiver.context.is_some(), "context"),
+        (receiver.remote.is_some(), "remote"),
+        (receiver.no_try_from.is_present(), "no_try_from"),
+    ] {
+        if present {
+            return Err(darling::Error::custom(format!(
+                "`serde_try_from` cannot be used with `{}`",
+                other
             ))
-        } else {
-            None
+            .with_span(option));
         }
     }
 
-    fn inherent_methods(&self) -> Vec<syn::ImplItemFn> {
-        let root = self.crate_root();
-        let mut methods = Vec::new();
+    let name = receiver.checker_name();
+    let (_, ty_generics, _) = receiver.generics.split_for_impl();
+    let checker = match &receiver.module {
+        Some(module) => {
+            let module = &module.name;
+            quote!(#module::#name #ty_generics)
+        }
+        None => quote!(#name #ty_generics),
+    };
+    let checker = checker.to_string();
+    Ok(parse_quote!(#[serde(try_from = #checker)]))
+}
 
-        if self.additional_errors.is_present() {
-            let error = &self.error;
-            let ident = self
-                .additional_errors_ident()
-                .expect("additional_errors_ident should exist");
+/// Remove the `staging` attributes from `input` and its fields and variants, which are
+/// only read while generating the checker.
+fn strip_staging_attrs(input: &mut syn::DeriveInput) {
+    fn strip(attrs: &mut Vec<syn::Attribute>) {
+        attrs.retain(|attr| !attr.path().is_ident("staging"));
+    }
 
-            let method: syn::ImplItemFn = parse_quote! {
-                /// Handle a result, pushing any errors into the `additional_errors` list.
-                pub fn handle<Val>(
-                    &mut self,
-                    result: #root::export::Result<Val, impl #root::export::Into<#error>>
-                ) -> #root::export::Option<Val> {
-                    match result {
-                        #root::export::Ok(value) => #root::export::Some(value),
-                        #root::export::Err(err) => {
-                            self.#ident.push(err.into());
-                            #root::export::None
-                        }
-                    }
+    strip(&mut input.attrs);
+    match &mut input.data {
+        syn::Data::Struct(data) => data
+            .fields
+            .iter_mut()
+            .for_each(|field| strip(&mut field.attrs)),
+        syn::Data::Enum(data) => {
+            for variant in &mut data.variants {
+                strip(&mut variant.attrs);
+                variant
+                    .fields
+                    .iter_mut()
+                    .for_each(|field| strip(&mut field.attrs));
+            }
+        }
+        syn::Data::Union(data) => data
+            .fields
+            .named
+            .iter_mut()
+            .for_each(|field| strip(&mut field.attrs)),
+    }
+}
+
+/// Generate the checker and everything else for one receiver.
+fn receiver_tokens(mut receiver: Receiver) -> darling::Result<TokenStream> {
+    if receiver.checker_name().unraw() == receiver.ident.unraw() {
+        return Err(darling::Error::custom(
+            "The checker cannot have the same name as the deriving type; set `prefix`, `suffix` or `name`",
+        )
+        .with_span(&receiver.ident));
+    }
+    let mut tokens = TokenStream::new();
+    match receiver.module.take() {
+        Some(module) => {
+            let vis = receiver.vis.clone();
+            receiver.nest_visibility();
+            receiver.to_tokens(&mut tokens);
+            let name = &module.name;
+            let reexports = if module.reexport.is_present() {
+                receiver.generated_types()
+            } else {
+                vec![]
+            };
+            let doc = format!(
+                " The checker for [`{}`] and the types generated with it.",
+                receiver.ident.unraw()
+            );
+            let doc_hidden = receiver.doc_hidden();
+            tokens = quote! {
+                #[doc = #doc]
+                #doc_hidden
+                #vis mod #name {
+                    use super::*;
+
+                    #tokens
                 }
+
+                #(#vis use self::#name::#reexports;)*
             };
-            methods.push(method);
         }
+        None => receiver.to_tokens(&mut tokens),
+    }
+    Ok(tokens)
+}
 
-        methods
+/// Reject inputs the derive cannot handle with a diagnostic spanned on the offending item.
+fn check_shape(input: &syn::DeriveInput) -> darling::Result<()> {
+    match &input.data {
+        syn::Data::Union(data) => Err(darling::Error::custom(
+            "Staging cannot be derived for unions",
+        )
+        .with_span(&data.union_token)),
+        syn::Data::Struct(data) if matches!(data.fields, syn::Fields::Unit) => Err(
+            darling::Error::custom("Staging cannot be derived for unit structs")
+                .with_span(&input.ident),
+        ),
+        _ => Ok(()),
     }
 }
 
-impl ToTokens for Receiver {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self {
-            ident,
-            data,
-            vis,
-            attrs,
-            generics,
-            derive,
-            ..
-        } = self;
+#[derive(Debug, Clone, FromField)]
+#[darling(attributes(staging), forward_attrs)]
+struct InputField {
+    ident: Option<syn::Ident>,
+    ty: syn::Type,
+    attrs: Vec<syn::Attribute>,
+    /// If set, the field is omitted from the checker and filled in during finalization,
+    /// either with `Default::default()` or the provided expression.
+    skip: Option<Override<syn::Expr>>,
+    /// If set, the field is stored as its plain type in the checker and moved directly
+    /// into the target during finalization.
+    infallible: Flag,
+    /// Value to use in place of the field when it holds an error, instead of recording
+    /// that error. A bare `default` uses `Default::default()`.
+    default: Option<Override<syn::Expr>>,
+    /// Recovery function `fn(E) -> Result<T, E>` called when the field holds an error,
+    /// before that error is recorded or replaced by `default`.
+    or_else: Option<Path>,
+    /// Error type for this field, which must implement `Into` for the struct's error type
+    /// (defaults to the struct's `error` if not specified)
+    error: Option<Path>,
+    /// Function applied to the field's error before it is recorded, e.g. to attach context
+    map_err: Option<Path>,
+    /// Module whose `Staged` type is stored in the checker in place of the field type,
+    /// and whose `finalize` function converts it to the field type during finalization.
+    with: Option<Path>,
+    /// If set, the field's type also derives `Staging` and its checker is stored in the
+    /// checker. It is finalized with its `TryFrom` impl, and the resulting error is
+    /// converted via `Into` and recorded as a single error, unless `merge_errors` is set.
+    nested: Option<Nested>,
+    /// If set, a collection field such as `Vec<T>` is staged as `Vec<Result<T, E>>`, and
+    /// every failing element is reported along with its index, as an `ElementError`
+    /// converted via `Into` unless a function `fn(usize, E) -> E` is provided.
+    elements: Option<Override<Path>>,
+    /// If set, a map field such as `HashMap<K, V>` is staged as `HashMap<K, Result<V, E>>`,
+    /// and every failing entry is reported. Providing a function `fn(&K, E) -> E` allows
+    /// attaching the entry's key to its error.
+    entries: Option<Override<Path>>,
+    /// If set, an `Option<T>` field is staged as `Option<Result<T, E>>` so that an absent
+    /// value does not need an error.
+    optional: Flag,
+    /// If set, a `Spanned<T, S>` field is staged as `Spanned<Result<T, E>, S>`, and its
+    /// error is recorded as `Spanned<E, S>` so it retains the span.
+    spanned: Flag,
+    /// If set, the field is staged as `Result<T, AtLeastOne<E>>` so it can hold several
+    /// errors, and every one of them is reported.
+    multiple_errors: Flag,
+    /// Name for this field in the checker (defaults to the field's own name)
+    rename: Option<Ident>,
+    /// Function `fn(&T) -> Result<(), E>` run on the field's value during finalization.
+    /// A failure is handled like any other error in the field.
+    validate: Option<Path>,
+    /// Async function `fn(&T) -> impl Future<Output = Result<(), E>>` awaited on the
+    /// field's value by `try_finalize_async`. Requires the `async` feature.
+    validate_async: Option<Path>,
+    /// Other fields that must also be provided for this field to be accepted. Each
+    /// `requires(field = other, error = expr)` records `error` if this field is
+    /// provided but `other` is not.
+    #[darling(multiple)]
+    requires: Vec<Constraint>,
+    /// Other fields that must not be provided alongside this field. Each
+    /// `conflicts_with(field = other, error = expr)` records `error` if both this field
+    /// and `other` are provided.
+    #[darling(multiple)]
+    conflicts_with: Vec<Constraint>,
+    /// Label for the errors from this field, used to group them in `errors_by_group`
+    group: Option<String>,
+    /// Position of this field's errors among those found while finalizing; fields are
+    /// visited in ascending order, then in declaration order (defaults to `0`)
+    order: Option<i32>,
+    /// If set, the field's setter accepts any `impl Into<T>`
+    into: Flag,
+    /// If set, the field's value is shown as `<redacted>` when the checker derives `Debug`.
+    secret: Flag,
+    /// Contents of `#[serde(...)]` attributes to put on the checker field
+    #[darling(multiple)]
+    serde: Vec<AttrArgs>,
+    /// Attributes to put on the checker field, written as `attrs(#[a], #[b])`
+    #[darling(rename = "attrs", multiple)]
+    checker_attrs: Vec<AttrList>,
+    /// Visibility of this field in the checker (defaults to the struct's `field_vis`)
+    #[darling(rename = "vis")]
+    checker_vis: Option<syn::Visibility>,
+}
 
-        let root = self.crate_root();
-        let checker_name = self.checker_name();
-        let final_error = self.final_error();
+impl InputField {
+    /// Check option combinations that cannot be expressed in a single attribute.
+    fn validate(&self) -> darling::Result<()> {
+        let mut errors = darling::Error::accumulator();
+        let span = self.span();
 
-        let derive = derive.as_ref().map(|pl| {
-            quote! {
-                #[derive(#(#pl),*)]
-            }
-        });
+        let modes = self.storage_modes();
+        if modes.len() > 1 {
+            errors.push(
+                darling::Error::custom(format!(
+                    "`{}` cannot be used together",
+                    modes.join("` and `")
+                ))
+                .with_span(&span),
+            );
+        }
 
-        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        if let Some(Nested { checker: None, .. }) = &self.nested
+            && inferred_checker(&self.ty).is_none()
+        {
+            errors.push(
+                darling::Error::custom(
+                    "Cannot infer the checker type for this field; specify it with `nested = Type`",
+                )
+                .with_span(&self.ty),
+            );
+        }
 
-        let fields = data
-            .as_ref()
-            .map_struct_fields(|field| ReceiverField {
-                receiver: self,
-                field,
-            })
-            .take_struct()
-            .expect("Only structs are supported")
-            .fields;
+        if self.elements.is_some() && type_arguments(&self.ty).is_empty() {
+            errors.push(
+                darling::Error::custom("`elements` requires a collection type such as `Vec<T>`")
+                    .with_span(&self.ty),
+            );
+        }
 
-        let field_decls = fields.iter().map(ReceiverField::field_decl);
-        let take_errors = fields.iter().map(ReceiverField::take_error);
-        let initializers = fields.iter().map(ReceiverField::initializer);
+        if self.entries.is_some() && type_arguments(&self.ty).len() < 2 {
+            errors.push(
+                darling::Error::custom("`entries` requires a map type such as `HashMap<K, V>`")
+                    .with_span(&self.ty),
+            );
+        }
 
-        let methods = self.inherent_methods();
+        if self.optional.is_present() && type_arguments(&self.ty).is_empty() {
+            errors.push(
+                darling::Error::custom("`optional` requires a field of type `Option<T>`")
+                    .with_span(&self.ty),
+            );
+        }
 
-        let inherent_impl = if methods.is_empty() {
-            None
-        } else {
-            Some(quote! {
-                impl #impl_generics #checker_name #ty_generics #where_clause {
-                    #(#methods)*
-                }
-            })
-        };
+        if self.spanned.is_present() && type_arguments(&self.ty).is_empty() {
+            errors.push(
+                darling::Error::custom("`spanned` requires a field of type `Spanned<T, S>`")
+                    .with_span(&self.ty),
+            );
+        }
 
-        let errors_decl: Option<syn::Field> = self.additional_errors_ident().map(|ident| {
-            let error = &self.error;
-            parse_quote! {
-                pub #ident: #root::export::Vec<#error>
+        if let Some(rename) = &self.rename {
+            if self.ident.is_none() {
+                errors.push(
+                    darling::Error::custom("`rename` cannot be used on tuple fields")
+                        .with_span(rename),
+                );
             }
-        });
 
-        let errors_init: syn::Expr = if let Some(ident) = self.additional_errors_ident() {
-            parse_quote! {checker.#ident}
-        } else {
-            parse_quote!(#root::export::Vec::new())
-        };
+            if self.skip.is_some() {
+                errors.push(
+                    darling::Error::custom("`rename` cannot be used with `skip`").with_span(rename),
+                );
+            }
+        }
 
-        tokens.append_all(quote! {
-            #derive
-            #(#attrs)*
-            #vis struct #checker_name #ty_generics #where_clause {
-                #(#field_decls,)*
-                #errors_decl
+        if let Some(validate_async) = &self.validate_async {
+            if !cfg!(feature = "async") {
+                errors.push(
+                    darling::Error::custom(
+                        "`validate_async` requires the `async` feature of `staging`",
+                    )
+                    .with_span(validate_async),
+                );
             }
 
-            #inherent_impl
+            if self.with.is_some() {
+                errors.push(
+                    darling::Error::custom("`validate_async` cannot be used with `with`")
+                        .with_span(validate_async),
+                );
+            }
+        }
 
-            impl #impl_generics #root::export::TryFrom<#checker_name> for #ident #ty_generics #where_clause {
-                type Error = #final_error;
+        if self.skip.is_some() && self.into.is_present() {
+            errors.push(
+                darling::Error::custom("`into` cannot be used with `skip`")
+                    .with_span(&self.into.span()),
+            );
+        }
 
-                fn try_from(checker: #checker_name) -> #root::export::Result<Self, Self::Error> {
-                    let mut __errors = #errors_init;
-                    #(#take_errors);*
+        if self.skip.is_some()
+            && let Some(group) = &self.group
+        {
+            errors.push(
+                darling::Error::custom("`group` cannot be used with `skip`").with_span(group),
+            );
+        }
 
-                    if !__errors.is_empty() {
-                        return #root::export::Err(__errors.into_iter().collect());
-                    }
+        if self.skip.is_some() {
+            for (name, constraint) in self.constraints() {
+                errors.push(
+                    darling::Error::custom(format!("`{}` cannot be used with `skip`", name))
+                        .with_span(&constraint.field),
+                );
+            }
+        }
 
-                    #root::export::Ok(#ident {
-                        #(#initializers),*
-                    })
+        if let Some(mode) = modes.first() {
+            for option in self.result_options() {
+                if mode_allows(mode, option) {
+                    continue;
                 }
+
+                errors.push(
+                    darling::Error::custom(format!("`{}` cannot be used with `{}`", option, mode))
+                        .with_span(&span),
+                );
             }
-        });
+        }
+
+        errors.finish()
     }
-}
 
-struct ReceiverField<'a> {
-    receiver: &'a Receiver,
-    field: &'a Field,
-}
+    /// Name of the field in the checker, or `None` if it is skipped or positional.
+    fn checker_ident(&self) -> Option<&Ident> {
+        if self.skip.is_some() {
+            return None;
+        }
 
-impl<'a> ReceiverField<'a> {
-    fn field_decl(&self) -> syn::Field {
-        let ident = &self.field.ident;
-        let ty = self.field_type();
+        self.rename.as_ref().or(self.ident.as_ref())
+    }
+
+    fn span(&self) -> proc_macro2::Span {
+        self.ident
+            .as_ref()
+            .map(Ident::span)
+            .unwrap_or_else(|| self.ty.span())
+    }
 
-        parse_quote! {
-            pub #ident: #ty
+    /// Whether the field is `nested` and reports each of its checker's errors.
+    fn merges_errors(&self) -> bool {
+        self.nested
+            .as_ref()
+            .is_some_and(|nested| nested.merge_errors.is_present())
+    }
+
+    /// Options that store the field as something other than `Result<T, E>` in the
+    /// checker; at most one may be set.
+    fn storage_modes(&self) -> Vec<&'static str> {
+        let mut modes = vec![];
+        if self.skip.is_some() {
+            modes.push("skip");
+        }
+        if self.infallible.is_present() {
+            modes.push("infallible");
+        }
+        if self.nested.is_some() {
+            modes.push("nested");
+        }
+        if self.elements.is_some() {
+            modes.push("elements");
+        }
+        if self.entries.is_some() {
+            modes.push("entries");
+        }
+        if self.optional.is_present() {
+            modes.push("optional");
         }
+        if self.spanned.is_present() {
+            modes.push("spanned");
+        }
+        if self.multiple_errors.is_present() {
+            modes.push("multiple_errors");
+        }
+        modes
     }
 
-    fn field_type(&self) -> syn::Type {
-        let ty = &self.field.ty;
-        let error = &self.receiver.error;
-        let root = self.receiver.crate_root();
-        parse_quote_spanned! {self.field.ty.span()=>
-            #root::export::Result<#ty, #error>
+    /// Constraints on other fields, along with the option that declared them.
+    fn constraints(&self) -> impl Iterator<Item = (&'static str, &Constraint)> {
+        let requires = self.requires.iter().map(|c| ("requires", c));
+        let conflicts_with = self.conflicts_with.iter().map(|c| ("conflicts_with", c));
+        requires.chain(conflicts_with)
+    }
+
+    /// Options that can make finalization fail even when the field holds no error.
+    fn finalize_options(&self) -> Vec<&'static str> {
+        let mut options = vec![];
+        if self.or_else.is_some() {
+            options.push("or_else");
+        }
+        if self.with.is_some() {
+            options.push("with");
         }
+        if self.validate.is_some() {
+            options.push("validate");
+        }
+        if self.validate_async.is_some() {
+            options.push("validate_async");
+        }
+        if !self.requires.is_empty() {
+            options.push("requires");
+        }
+        if !self.conflicts_with.is_empty() {
+            options.push("conflicts_with");
+        }
+        options
     }
 
-    fn take_error(&self) -> syn::Stmt {
-        let ident = self
-            .field
-            .ident
-            .as_ref()
-            .expect("Unnamed fields not supported");
+    /// Options that only apply to fields stored as `Result<T, E>` in the checker.
+    fn result_options(&self) -> Vec<&'static str> {
+        let mut options = vec![];
+        if self.default.is_some() {
+            options.push("default");
+        }
+        if self.or_else.is_some() {
+            options.push("or_else");
+        }
+        if self.error.is_some() {
+            options.push("error");
+        }
+        if self.map_err.is_some() {
+            options.push("map_err");
+        }
+        if self.with.is_some() {
+            options.push("with");
+        }
+        if self.validate.is_some() {
+            options.push("validate");
+        }
+        if self.validate_async.is_some() {
+            options.push("validate_async");
+        }
+        options
+    }
+}
 
-        let root = self.receiver.crate_root();
-        parse_quote! {
-            let #ident = match checker.#ident {
-                #root::export::Result::Ok(value) => Some(value),
-                #root::export::Result::Err(err) => {
-                    __errors.push(err);
-                    None
+/// A relationship between two fields of the same struct or variant, checked during
+/// finalization.
+#[derive(Debug, Clone, FromMeta)]
+struct Constraint {
+    /// The other field
+    field: Ident,
+    /// Error recorded when the constraint does not hold, converted via `Into`
+    error: syn::Expr,
+}
+
+/// A group of fields written as `(a, b, c, error = expr)`, of which at least one must be
+/// provided during finalization.
+#[derive(Debug, Clone)]
+struct AtLeastOneOf {
+    fields: Vec<Ident>,
+    /// Error recorded when none of the fields are provided, converted via `Into`
+    error: syn::Expr,
+}
+
+impl FromMeta for AtLeastOneOf {
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
+        let mut fields = vec![];
+        let mut error = None;
+
+        for item in items {
+            match item {
+                darling::ast::NestedMeta::Meta(syn::Meta::Path(path)) => match path.get_ident() {
+                    Some(ident) => fields.push(ident.clone()),
+                    None => {
+                        errors.push(darling::Error::custom("Expected a field name").with_span(path))
+                    }
+                },
+                darling::ast::NestedMeta::Meta(meta @ syn::Meta::NameValue(nv))
+                    if nv.path.is_ident("error") =>
+                {
+                    if error.is_some() {
+                        errors.push(darling::Error::duplicate_field("error").with_span(meta));
+                    } else {
+                        error = errors.handle(syn::Expr::from_meta(meta));
+                    }
                 }
-            };
+                other => errors.push(darling::Error::unsupported_format("item").with_span(other)),
+            }
+        }
+
+        if fields.is_empty() {
+            errors.push(darling::Error::too_few_items(1));
+        }
+
+        if error.is_none() {
+            errors.push(darling::Error::missing_field("error"));
         }
+
+        errors.finish()?;
+        Ok(AtLeastOneOf {
+            fields,
+            error: error.expect("missing `error` was reported above"),
+        })
     }
+}
 
-    fn initializer(&self) -> syn::FieldValue {
-        let ident = self
-            .field
-            .ident
-            .as_ref()
-            .expect("Unnamed fields not supported");
-        parse_quote! {
-            #ident: #ident.unwrap()
+/// Fields that exist only on the checker, written as `extra(name: Type, ...)`. Each can
+/// have its own attributes and visibility, as in a struct declaration.
+#[derive(Debug, Clone, Default)]
+struct Extra(Vec<syn::Field>);
+
+impl FromMeta for Extra {
+    fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+        let syn::Meta::List(list) = item else {
+            return Err(darling::Error::unsupported_format("non-list").with_span(item));
+        };
+
+        let fields = list.parse_args_with(|input: syn::parse::ParseStream| {
+            Punctuated::<syn::Field, syn::Token![,]>::parse_terminated_with(
+                input,
+                syn::Field::parse_named,
+            )
+        })?;
+        Ok(Extra(fields.into_iter().collect()))
+    }
+}
+
+/// The tokens inside an option's list, kept as they are so they can be placed in an
+/// attribute for another crate, e.g. `rename_all = "camelCase"` from
+/// `serde(rename_all = "camelCase")`.
+#[derive(Debug, Clone)]
+struct AttrArgs(TokenStream);
+
+impl FromMeta for AttrArgs {
+    fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+        match item {
+            syn::Meta::List(list) => Ok(AttrArgs(list.tokens.clone())),
+            _ => Err(darling::Error::unsupported_format("non-list").with_span(item)),
         }
     }
 }
 
-pub mod export {
-    pub use std::convert::{Into, TryFrom};
-    pub use std::option::Option::{self, None, Some};
-    pub use std::result::Result::{self, Err, Ok};
-    pub use std::vec::Vec;
+impl ToTokens for AttrArgs {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+/// The options in `serde(...)` lists that the `Deserialize` impl generated by
+/// `deserialize` reads. Serde's other options are not supported there.
+#[derive(Debug, Default)]
+struct SerdeOptions {
+    rename: Option<String>,
+    rename_all: Option<String>,
+    aliases: Vec<String>,
+    deny_unknown_fields: bool,
+}
+
+impl SerdeOptions {
+    /// Read `args`, reporting any option not named in `supported`.
+    fn parse(args: &[AttrArgs], supported: &[&str]) -> darling::Result<Self> {
+        let mut options = Self::default();
+        let mut errors = darling::Error::accumulator();
+        for args in args {
+            let Some(metas) = errors.handle(
+                Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated
+                    .parse2(args.0.clone())
+                    .map_err(darling::Error::from),
+            ) else {
+                continue;
+            };
+
+            for meta in metas {
+                let name = meta
+                    .path()
+                    .get_ident()
+                    .map(Ident::to_string)
+                    .unwrap_or_default();
+                if !supported.contains(&name.as_str()) {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "`deserialize` does not support `serde({})`",
+                            meta.path().to_token_stream()
+                        ))
+                        .with_span(&meta),
+                    );
+                    continue;
+                }
+
+                if name == "deny_unknown_fields" {
+                    errors.handle(meta.require_path_only().map_err(darling::Error::from));
+                    options.deny_unknown_fields = true;
+                    continue;
+                }
+
+                let Some(value) = errors.handle(String::from_meta(&meta)) else {
+                    continue;
+                };
+                match name.as_str() {
+                    "rename" => options.rename = Some(value),
+                    "alias" => options.aliases.push(value),
+                    _ => {
+                        if rename_case("a_b", &value).is_none() {
+                            errors.push(
+                                darling::Error::custom(format!(
+                                    "unknown `rename_all` case `{}`",
+                                    value
+                                ))
+                                .with_span(&meta),
+                            );
+                        }
+                        options.rename_all = Some(value);
+                    }
+                }
+            }
+        }
+
+        errors.finish_with(options)
+    }
+}
+
+/// Attributes written in an option's list, such as `attrs(#[allow(dead_code)], #[derive(Clone)])`.
+/// The commas between them are optional.
+#[derive(Debug, Clone, Default)]
+struct AttrList(Vec<syn::Attribute>);
+
+impl FromMeta for AttrList {
+    fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+        let syn::Meta::List(list) = item else {
+            return Err(darling::Error::unsupported_format("non-list").with_span(item));
+        };
+
+        let attrs = list.parse_args_with(|input: syn::parse::ParseStream| {
+            let mut attrs = vec![];
+            while !input.is_empty() {
+                attrs.extend(syn::Attribute::parse_outer(input)?);
+                if !input.is_empty() {
+                    input.parse::<syn::Token![,]>()?;
+                }
+            }
+            Ok(attrs)
+        })?;
+        Ok(AttrList(attrs))
+    }
+}
+
+impl ToTokens for AttrList {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(&self.0);
+    }
+}
+
+/// Where the checker stores errors that are not tied to a single field, written as a
+/// bare `additional_errors`, as `additional_errors = "name"`, or as
+/// `additional_errors(name = "name", vis = "pub(crate)", container = BTreeSet::<Error>, position = "last")`.
+#[derive(Debug, Clone, Default, FromMeta)]
+#[darling(
+    from_word = AdditionalErrors::from_word,
+    from_expr = AdditionalErrors::from_name
+)]
+struct AdditionalErrors {
+    /// Name of the checker field (defaults to `additional_errors`)
+    name: Option<Ident>,
+    /// Visibility of the checker field (defaults to `pub`)
+    vis: Option<syn::Visibility>,
+    /// Type of the checker field, which must implement `Default`, `Extend<E>` and
+    /// `IntoIterator<Item = E>` (defaults to `Vec<E>`)
+    container: Option<Path>,
+    /// Whether finalizing reports these errors before or after the field errors
+    #[darling(default)]
+    position: Position,
+}
+
+/// Where a list of errors goes relative to the field errors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, FromMeta)]
+enum Position {
+    #[default]
+    First,
+    Last,
+}
+
+impl AdditionalErrors {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+
+    fn from_name(expr: &syn::Expr) -> darling::Result<Self> {
+        Ok(Self {
+            name: Some(Ident::from_expr(expr)?),
+            ..Self::default()
+        })
+    }
+}
+
+/// Checker of a field whose type also derives `Staging`, written as a bare `nested`, as
+/// `nested = Checker`, or as `nested(checker = Checker, merge_errors)`.
+#[derive(Debug, Clone, Default, FromMeta)]
+#[darling(from_word = Nested::from_word, from_expr = Nested::from_checker)]
+struct Nested {
+    /// The checker type (inferred by appending `Staging` to the field type's name)
+    checker: Option<Path>,
+    /// If set, the checker is finalized with `into_result` and each of its errors is
+    /// converted via `Into` and merged into this checker's errors.
+    merge_errors: Flag,
+}
+
+impl Nested {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+
+    fn from_checker(expr: &syn::Expr) -> darling::Result<Self> {
+        Ok(Self {
+            checker: Some(Path::from_expr(expr)?),
+            ..Self::default()
+        })
+    }
+}
+
+/// Module that the checker and everything generated for it are placed in, written as
+/// `module = "name"` or `module(name = "name", reexport)`.
+#[derive(Debug, Clone, FromMeta)]
+#[darling(from_expr = Module::from_name)]
+struct Module {
+    name: Ident,
+    /// If set, the generated types are re-exported next to the deriving type.
+    reexport: Flag,
+}
+
+impl Module {
+    fn from_name(expr: &syn::Expr) -> darling::Result<Self> {
+        Ok(Self {
+            name: Ident::from_expr(expr)?,
+            reexport: Flag::default(),
+        })
+    }
+}
+
+/// Function run on the target once it has been built, written as `post_validate = path`
+/// for a function returning `Result<(), E>`, or as `post_validate(path = path, multiple)`
+/// for one returning `Result<(), impl IntoIterator<Item = E>>`.
+#[derive(Debug, Clone, FromMeta)]
+#[darling(from_expr = PostValidate::from_path)]
+struct PostValidate {
+    path: Path,
+    /// If set, the function returns every error it finds rather than a single one.
+    multiple: Flag,
+}
+
+impl PostValidate {
+    fn from_path(expr: &syn::Expr) -> darling::Result<Self> {
+        Ok(Self {
+            path: Path::from_expr(expr)?,
+            multiple: Flag::default(),
+        })
+    }
+}
+
+/// Type that finalizing collects the errors into, written as `error_storage = Type` or
+/// `error_storage(container = Type, overflow = expr)`.
+#[derive(Debug, Clone, FromMeta)]
+#[darling(from_expr = ErrorStorage::from_container)]
+struct ErrorStorage {
+    container: Path,
+    /// If set, the container has a fixed capacity: errors are added with a `push` method
+    /// that returns `Err` once it is full, in which case its last error is replaced with
+    /// this one.
+    overflow: Option<syn::Expr>,
+}
+
+impl ErrorStorage {
+    fn from_container(expr: &syn::Expr) -> darling::Result<Self> {
+        Ok(Self {
+            container: Path::from_expr(expr)?,
+            overflow: None,
+        })
+    }
+}
+
+/// How the generated `merge` method chooses between two checkers, written as
+/// `merge(prefer = "self", additional_errors = "other")`. A bare `merge` uses the defaults.
+#[derive(Debug, Clone, FromMeta)]
+struct MergeOptions {
+    /// Which checker's field is kept when both hold a value, or both hold an error
+    #[darling(default = Prefer::other)]
+    prefer: Prefer,
+    /// Which checker's additional errors come first in the merged list
+    #[darling(default = Prefer::current)]
+    additional_errors: Prefer,
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        Self {
+            prefer: Prefer::Other,
+            additional_errors: Prefer::Current,
+        }
+    }
+}
+
+/// One of the two checkers being merged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromMeta)]
+enum Prefer {
+    #[darling(rename = "self")]
+    Current,
+    #[darling(rename = "other")]
+    Other,
+}
+
+impl Prefer {
+    fn current() -> Self {
+        Self::Current
+    }
+
+    fn other() -> Self {
+        Self::Other
+    }
+}
+
+#[derive(Debug, Clone, FromVariant)]
+#[darling(attributes(staging), forward_attrs(doc, cfg))]
+struct Variant {
+    ident: syn::Ident,
+    attrs: Vec<syn::Attribute>,
+    fields: Fields<InputField>,
+}
+
+#[derive(Debug, Clone, FromDeriveInput)]
+#[darling(
+    attributes(staging),
+    forward_attrs(doc, cfg),
+    supports(struct_named, struct_tuple, enum_any)
+)]
+struct Receiver {
+    ident: syn::Ident,
+    vis: syn::Visibility,
+    generics: syn::Generics,
+    attrs: Vec<syn::Attribute>,
+    data: Data<Variant, InputField>,
+    /// Traits that the generated struct should derive
+    derive: Option<PathList>,
+    /// Name for the generated checker type
+    name: Option<Ident>,
+    /// Text put before the deriving type's name to name the checker (defaults to the
+    /// `Naming` passed in, which is empty unless set through the API)
+    prefix: Option<String>,
+    /// Text put after the deriving type's name to name the checker (defaults to the
+    /// `Naming` passed in, which is `Staging` unless set through the API)
+    suffix: Option<String>,
+    /// Naming convention used when `name`, `prefix` or `suffix` is not set, from the
+    /// `StagingOptions` passed in
+    #[darling(skip)]
+    naming: Naming,
+    /// Visibility of the checker and its methods (defaults to the deriving type's
+    /// visibility)
+    #[darling(rename = "vis")]
+    checker_vis: Option<syn::Visibility>,
+    /// Path to the error type; required unless `generic_error` is set, in which case it
+    /// is filled in with the type parameter during validation
+    error: Option<Path>,
+    /// If set, the checker has a type parameter (named `E` unless a name is given) that
+    /// is used as its error type.
+    generic_error: Option<Override<Ident>>,
+    /// The final error type to return (defaults to `error` if not specified)
+    final_error: Option<Path>,
+    /// Function `fn(Vec<E>) -> FinalError` that combines the errors, used in place of
+    /// collecting them with `FromIterator`
+    combine: Option<Path>,
+    /// If set, the `TryFrom` impl returns the first error it finds, converted via `Into`
+    /// to the final error type, instead of collecting every error.
+    fail_fast: Flag,
+    /// If set, the `TryFrom` impl converts the errors via `Into` from an `AtLeastOne<E>`,
+    /// so the final error type can rely on always holding at least one error.
+    non_empty: Flag,
+    /// If set, each error is wrapped in a `FieldError` naming the field it came from
+    /// before the errors are combined.
+    field_errors: Flag,
+    /// If set, finalizing keeps only the first of any errors that are equal.
+    dedup: Flag,
+    /// Type that finalizing collects the errors into in place of `Vec<E>`, such as a
+    /// `SmallVec` that keeps the first few errors inline.
+    error_storage: Option<ErrorStorage>,
+    /// Type that each field's `Result` is stored as in the checker, such as `Field`. It
+    /// takes the value and error types as parameters, converts to and from the `Result`
+    /// with `From`, and borrows it with `AsRef` and `AsMut`.
+    field_type: Option<Path>,
+    /// If set, emit an enum with a unit variant for each field of the struct, named
+    /// `<Target>Field` unless a name is given.
+    field_enum: Option<Override<Ident>>,
+    /// Crate root path (defaults to `::staging_core` if not specified)
+    crate_root: Option<Path>,
+    /// If set, the generated struct will have an extra `Vec` to store errors that
+    /// could not be associated with a specific field.
+    additional_errors: Option<SpannedValue<AdditionalErrors>>,
+    /// If set, the checker has a `warnings` list of this type for non-fatal issues, which
+    /// `finalize_with_warnings` returns alongside the target.
+    warnings: Option<Path>,
+    /// If set, `finalize_with_warnings` fails only for additional errors whose `Severity`
+    /// is at least this level and passes the rest on as warnings. It replaces the `TryFrom`
+    /// impl, which has nowhere to put them.
+    threshold: Option<syn::Expr>,
+    /// Fields that exist only on the checker, such as provenance data. They start as
+    /// `Default::default()` and are dropped when the checker is finalized.
+    #[darling(default)]
+    extra: Extra,
+    /// Visibility of the checker's fields (defaults to `pub`)
+    field_vis: Option<syn::Visibility>,
+    /// Attributes to copy from each field onto the corresponding checker field.
+    /// `cfg` attributes and doc comments are always copied.
+    forward_attrs: Option<PathList>,
+    /// Contents of `#[serde(...)]` attributes to put on the checker
+    #[darling(multiple)]
+    serde: Vec<AttrArgs>,
+    /// Attributes to put on the checker, written as `attrs(#[a], #[b])`
+    #[darling(rename = "attrs", multiple)]
+    checker_attrs: Vec<AttrList>,
+    /// If set, each trait impl generated for the checker is marked
+    /// `#[automatically_derived]`.
+    automatically_derived: Flag,
+    /// If set, the checker and the other generated types are hidden from the docs.
+    doc_hidden: Flag,
+    /// If set, the checker is `#[non_exhaustive]`, so other crates create it with a
+    /// generated function rather than a struct expression.
+    non_exhaustive: Flag,
+    /// Groups of fields of which at least one must be provided, each recording its
+    /// `error` otherwise.
+    #[darling(multiple)]
+    at_least_one_of: Vec<AtLeastOneOf>,
+    /// If set, the checker has a `set_<field>` method for each field that stores a
+    /// successful value.
+    setters: Flag,
+    /// If set, the checker has a `<field>(&self)` and `<field>_mut(&mut self)` method for
+    /// each field that borrows what it holds.
+    accessors: Flag,
+    /// If set, the checker has a `merge` method that combines it with another checker,
+    /// keeping successful values over errors.
+    merge: Option<Override<MergeOptions>>,
+    /// If set, the checker has an `apply_to` method that writes each successful value
+    /// onto an existing target.
+    apply_to: Flag,
+    /// If set, emit a struct with an `Option` of each field of the target, named
+    /// `<Target>Patch` unless a name is given, and an `apply` method on the target.
+    patch: Option<Override<Ident>>,
+    /// If set, emit a `<Checker>Ref` struct that borrows each field of the checker, and
+    /// an `as_ref` method that creates it.
+    as_ref: Flag,
+    /// If set, the checker is an error-aggregating builder: it has setters, a `new`
+    /// function that starts each field as `Err(path("field"))`, and a `build` method.
+    builder: Option<Path>,
+    /// Error that each field needing a value holds in the checker created by the
+    /// generated `new` function.
+    missing: Option<syn::Expr>,
+    /// If set, the checker implements `From<Target>`, storing every field of the target
+    /// as a successful value.
+    from_target: Flag,
+    /// If set, the target implements `TryFrom<&Checker>`, finalizing a clone of the
+    /// checker so it can be finalized again later.
+    try_from_ref: Flag,
+    /// If set, the checker has a `validate(&self)` method that borrows every error it
+    /// currently holds.
+    #[darling(rename = "validate")]
+    validate_method: Flag,
+    /// If set, the checker has methods that inspect the errors it currently holds, such
+    /// as `errors(&self)`.
+    inspect: Flag,
+    /// If set, the checker has a `try_finalize` method that returns the checker itself
+    /// if it holds any errors.
+    try_finalize: Flag,
+    /// Function `fn(&Target) -> Result<(), E>` run on the target once it has been built,
+    /// whose error fails finalization.
+    post_validate: Option<PostValidate>,
+    /// Function run on `&Checker` before its fields are finalized, returning errors to
+    /// record alongside the additional errors.
+    pre_finalize: Option<Path>,
+    /// If set, finalizing takes a `&C` that is passed to every validator, and the
+    /// checker has a `try_into_with` method in place of the `TryFrom` impl.
+    context: Option<Path>,
+    /// Type the checker finalizes into in place of the deriving type, which is then a
+    /// local mirror of a type from another crate with the same fields.
+    remote: Option<Path>,
+    /// If set in one of several profiles, that profile's checker is the one the deriving
+    /// type's `Staged` impl names.
+    primary: Flag,
+    /// Whether this checker implements `Staged` for the deriving type: it has no sibling
+    /// profiles, or it is the `primary` one.
+    #[darling(skip)]
+    implements_staged: bool,
+    /// If set, the checker has a `finalize` method in place of the `TryFrom` impl.
+    no_try_from: Flag,
+    /// If set, the target implements `FromStaging<Checker>`, alongside the `TryFrom` impl
+    /// or in its place when `no_try_from` is set.
+    from_staging: Flag,
+    /// If set, the checker implements `serde::Deserialize`, storing each field's
+    /// deserialization error in that field. Requires the `serde` feature.
+    deserialize: Flag,
+    /// If set, the checker and everything generated for it are placed in this module.
+    module: Option<Module>,
+}
+
+impl Receiver {
+    /// Fill in the options that the deriving type does not set from those passed in.
+    fn apply_options(&mut self, options: &StagingOptions) {
+        fn set_flag(flag: &mut Flag, set: bool) {
+            if set && !flag.is_present() {
+                *flag = Flag::present();
+            }
+        }
+
+        fn set_default<T: Clone>(option: &mut Option<T>, default: &Option<T>) {
+            if option.is_none() {
+                option.clone_from(default);
+            }
+        }
+
+        self.naming = options.naming.clone();
+        set_default(&mut self.crate_root, &options.crate_root);
+        set_default(&mut self.error, &options.error);
+        set_default(&mut self.final_error, &options.final_error);
+        set_default(&mut self.name, &options.name);
+        set_default(&mut self.checker_vis, &options.vis);
+        set_default(&mut self.builder, &options.builder);
+        if !options.derive.is_empty() {
+            let mut derive = self
+                .derive
+                .take()
+                .map(|derive| derive.to_vec())
+                .unwrap_or_default();
+            for path in &options.derive {
+                if !derive.contains(path) {
+                    derive.push(path.clone());
+                }
+            }
+            self.derive = Some(PathList::from(derive));
+        }
+        if options.additional_errors && self.additional_errors.is_none() {
+            self.additional_errors = Some(SpannedValue::new(
+                AdditionalErrors::default(),
+                proc_macro2::Span::call_site(),
+            ));
+        }
+        set_flag(&mut self.setters, options.setters);
+        set_flag(&mut self.accessors, options.accessors);
+        set_flag(&mut self.inspect, options.inspect);
+        set_flag(&mut self.fail_fast, options.fail_fast);
+        set_flag(&mut self.field_errors, options.field_errors);
+    }
+
+    /// Check option combinations, reporting every problem on the receiver and its
+    /// fields at once rather than stopping at the first.
+    fn validate(mut self) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
+
+        match (&self.generic_error, &self.error) {
+            (None, None) => errors.push(darling::Error::missing_field("error")),
+            (Some(_), Some(error)) => errors.push(
+                darling::Error::custom("`error` cannot be used with `generic_error`")
+                    .with_span(error),
+            ),
+            (Some(_), None) => {
+                let param = self.error_param().expect("`generic_error` is set");
+                if self
+                    .generics
+                    .type_params()
+                    .any(|existing| existing.ident == param)
+                {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "`{}` is already a type parameter; name another with `generic_error = Name`",
+                            param
+                        ))
+                        .with_span(&param),
+                    );
+                }
+
+                let options = [
+                    ("builder", self.builder.is_some()),
+                    ("missing", self.missing.is_some()),
+                    ("at_least_one_of", !self.at_least_one_of.is_empty()),
+                ];
+                for (option, _) in options.into_iter().filter(|(_, used)| *used) {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "`{}` cannot be used with `generic_error`",
+                            option
+                        ))
+                        .with_span(&param),
+                    );
+                }
+
+                self.error = Some(parse_quote!(#param));
+            }
+            (None, Some(_)) => {}
+        }
+
+        if let Some(additional_errors) = &self.additional_errors {
+            match &self.data {
+                Data::Enum(_) => errors.push(
+                    darling::Error::custom("`additional_errors` is not supported on enums")
+                        .with_span(&additional_errors.span()),
+                ),
+                Data::Struct(fields) => match &additional_errors.name {
+                    Some(name) if fields.style.is_tuple() => errors.push(
+                        darling::Error::custom(
+                            "`additional_errors` cannot be renamed on tuple structs, where it is positional",
+                        )
+                        .with_span(name),
+                    ),
+                    Some(name) if fields.iter().any(|field| field.checker_ident() == Some(name)) => {
+                        errors.push(
+                            darling::Error::custom(format!(
+                                "`{}` is already a field; name the list of additional errors with `additional_errors = \"name\"`",
+                                name.unraw()
+                            ))
+                            .with_span(name),
+                        )
+                    }
+                    None if fields.iter().any(|field| {
+                        field
+                            .checker_ident()
+                            .is_some_and(|ident| ident == "additional_errors")
+                    }) =>
+                    {
+                        errors.push(
+                            darling::Error::custom(
+                                "`additional_errors` is already a field; name the list of additional errors with `additional_errors = \"name\"`",
+                            )
+                            .with_span(&additional_errors.span()),
+                        )
+                    }
+                    _ => {}
+                },
+            }
+        }
+
+        if let Some(warnings) = &self.warnings {
+            match &self.data {
+                Data::Enum(_) => errors.push(
+                    darling::Error::custom("`warnings` is not supported on enums")
+                        .with_span(warnings),
+                ),
+                Data::Struct(fields) => {
+                    if fields.iter().any(|field| {
+                        field
+                            .checker_ident()
+                            .is_some_and(|ident| ident == "warnings")
+                    }) {
+                        errors.push(
+                            darling::Error::custom(
+                                "`warnings` is already a field, so the checker cannot add its list of warnings",
+                            )
+                            .with_span(warnings),
+                        );
+                    }
+                }
+            }
+        }
+
+        if let Some(first) = self.extra.0.first() {
+            match &self.data {
+                Data::Enum(_) => errors.push(
+                    darling::Error::custom("`extra` is not supported on enums").with_span(first),
+                ),
+                Data::Struct(fields) if fields.style.is_tuple() => errors.push(
+                    darling::Error::custom("`extra` is not supported on tuple structs")
+                        .with_span(first),
+                ),
+                Data::Struct(fields) => {
+                    let mut taken: Vec<Ident> = fields
+                        .iter()
+                        .filter_map(InputField::checker_ident)
+                        .cloned()
+                        .chain(self.additional_errors_ident())
+                        .chain(
+                            self.warnings
+                                .as_ref()
+                                .map(|warnings| Ident::new("warnings", warnings.span())),
+                        )
+                        .collect();
+
+                    for extra in &self.extra.0 {
+                        let Some(ident) = &extra.ident else {
+                            continue;
+                        };
+
+                        if taken.contains(ident) {
+                            errors.push(
+                                darling::Error::custom(format!(
+                                    "`{}` is already a field of the checker",
+                                    ident.unraw()
+                                ))
+                                .with_span(ident),
+                            );
+                        } else {
+                            taken.push(ident.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(threshold) = &self.threshold {
+            for (present, option) in [
+                (self.additional_errors.is_some(), "additional_errors"),
+                (self.warnings.is_some(), "warnings"),
+            ] {
+                if !present {
+                    errors.push(
+                        darling::Error::custom(format!("`threshold` requires `{}`", option))
+                            .with_span(threshold),
+                    );
+                }
+            }
+
+            // Each of these finalizes the checker without returning warnings, so the
+            // errors below the threshold would be lost.
+            for (present, option) in [
+                (self.generic_error.is_some(), "generic_error"),
+                (self.context.is_some(), "context"),
+                (self.no_try_from.is_present(), "no_try_from"),
+                (self.from_staging.is_present(), "from_staging"),
+                (self.try_from_ref.is_present(), "try_from_ref"),
+                (self.try_finalize.is_present(), "try_finalize"),
+                (self.builder.is_some(), "builder"),
+            ] {
+                if present {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "`threshold` cannot be used with `{}`",
+                            option
+                        ))
+                        .with_span(threshold),
+                    );
+                }
+            }
+        }
+
+        if self.dedup.is_present() && self.generic_error.is_some() {
+            errors.push(
+                darling::Error::custom("`dedup` cannot be used with `generic_error`")
+                    .with_span(&self.dedup.span()),
+            );
+        }
+
+        if self.data.is_enum()
+            && let Some(field_vis) = &self.field_vis
+        {
+            errors.push(
+                darling::Error::custom("`field_vis` is not supported on enums")
+                    .with_span(field_vis),
+            );
+        }
+
+        if self.fail_fast.is_present()
+            && let Some(combine) = &self.combine
+        {
+            errors.push(
+                darling::Error::custom("`combine` cannot be used with `fail_fast`")
+                    .with_span(combine),
+            );
+        }
+
+        if self.non_empty.is_present() {
+            if let Some(combine) = &self.combine {
+                errors.push(
+                    darling::Error::custom("`combine` cannot be used with `non_empty`")
+                        .with_span(combine),
+                );
+            }
+
+            if self.fail_fast.is_present() {
+                errors.push(
+                    darling::Error::custom("`fail_fast` cannot be used with `non_empty`")
+                        .with_span(&self.fail_fast.span()),
+                );
+            }
+        }
+
+        if let Some(error_storage) = &self.error_storage {
+            for (present, option) in [
+                (self.non_empty.is_present(), "non_empty"),
+                (self.field_errors.is_present(), "field_errors"),
+                (self.dedup.is_present(), "dedup"),
+                (self.has_groups(), "group"),
+            ] {
+                if present {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "`error_storage` cannot be used with `{}`",
+                            option
+                        ))
+                        .with_span(&error_storage.container),
+                    );
+                }
+            }
+        }
+
+        if let Some(field_enum) = &self.field_enum {
+            let message = match &self.data {
+                Data::Enum(_) => Some("`field_enum` is not supported on enums"),
+                Data::Struct(fields) if fields.style == Style::Tuple => {
+                    Some("`field_enum` is not supported on tuple structs")
+                }
+                Data::Struct(_) => None,
+            };
+            if let Some(message) = message {
+                let error = darling::Error::custom(message);
+                errors.push(match field_enum {
+                    Override::Explicit(name) => error.with_span(name),
+                    Override::Inherit => error.with_span(&self.ident),
+                });
+            }
+        }
+
+        if self.field_errors.is_present() {
+            if let Some(additional_errors) = &self.additional_errors {
+                errors.push(
+                    darling::Error::custom(
+                        "`additional_errors` cannot be used with `field_errors`, as those errors have no field",
+                    )
+                    .with_span(&additional_errors.span()),
+                );
+            }
+
+            for group in &self.at_least_one_of {
+                errors.push(
+                    darling::Error::custom(
+                        "`at_least_one_of` cannot be used with `field_errors`, as its error has no single field",
+                    )
+                    .with_span(&group.error),
+                );
+            }
+        }
+
+        for (hook, path) in [
+            (
+                "post_validate",
+                self.post_validate.as_ref().map(|hook| &hook.path),
+            ),
+            ("pre_finalize", self.pre_finalize.as_ref()),
+        ] {
+            let Some(path) = path else {
+                continue;
+            };
+
+            for (present, option) in [
+                (self.field_errors.is_present(), "field_errors"),
+                (self.try_finalize.is_present(), "try_finalize"),
+            ] {
+                if present {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "`{}` cannot be used with `{}`",
+                            hook, option
+                        ))
+                        .with_span(path),
+                    );
+                }
+            }
+        }
+
+        if let Some(context) = &self.context
+            && self.try_from_ref.is_present()
+        {
+            errors.push(
+                darling::Error::custom("`context` cannot be used with `try_from_ref`")
+                    .with_span(context),
+            );
+        }
+
+        if self.no_try_from.is_present() {
+            for (present, option) in [
+                (self.context.is_some(), "context"),
+                (self.try_from_ref.is_present(), "try_from_ref"),
+            ] {
+                if present {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "`no_try_from` cannot be used with `{}`",
+                            option
+                        ))
+                        .with_span(&self.no_try_from.span()),
+                    );
+                }
+            }
+        }
+
+        if self.deserialize.is_present() {
+            let span = self.deserialize.span();
+            if !cfg!(feature = "serde") {
+                errors.push(
+                    darling::Error::custom(
+                        "`deserialize` requires the `serde` feature of `staging`",
+                    )
+                    .with_span(&span),
+                );
+            }
+
+            let message = match &self.data {
+                Data::Enum(_) => Some("`deserialize` is not supported on enums"),
+                Data::Struct(fields) if fields.style == Style::Tuple => {
+                    Some("`deserialize` is not supported on tuple structs")
+                }
+                Data::Struct(_) => None,
+            };
+            if let Some(message) = message {
+                errors.push(darling::Error::custom(message).with_span(&span));
+            }
+
+            if self.builder.is_none() && self.missing.is_none() {
+                errors.push(
+                    darling::Error::custom(
+                        "`deserialize` requires `builder` or `missing` for the fields a document leaves out",
+                    )
+                    .with_span(&span),
+                );
+            }
+
+            if self.generic_error.is_some() {
+                errors.push(
+                    darling::Error::custom("`deserialize` cannot be used with `generic_error`")
+                        .with_span(&span),
+                );
+            }
+
+            if let Some(derive) = &self.derive {
+                for path in derive.iter().filter(|path| is_deserialize(path)) {
+                    errors.push(
+                        darling::Error::custom(
+                            "`deserialize` cannot be used with `derive(Deserialize)`",
+                        )
+                        .with_span(path),
+                    );
+                }
+            }
+
+            errors.handle(SerdeOptions::parse(
+                &self.serde,
+                &["rename_all", "deny_unknown_fields"],
+            ));
+            for field in self.fields() {
+                for mode in field.storage_modes() {
+                    if matches!(mode, "elements" | "entries" | "spanned") {
+                        errors.push(
+                            darling::Error::custom(format!(
+                                "`deserialize` cannot be used with `{}` fields",
+                                mode
+                            ))
+                            .with_span(&field.span()),
+                        );
+                    }
+                }
+                if field.merges_errors() {
+                    errors.push(
+                        darling::Error::custom(
+                            "`deserialize` cannot be used with `nested(merge_errors)` fields",
+                        )
+                        .with_span(&field.span()),
+                    );
+                }
+                errors.handle(SerdeOptions::parse(&field.serde, &["rename", "alias"]));
+                // Each value is buffered before it is read, so there is nothing left in
+                // the document to borrow from.
+                if let Some(reference) = find_reference(&field.ty).filter(|_| field.skip.is_none())
+                {
+                    errors.push(
+                        darling::Error::custom(
+                            "`deserialize` cannot read borrowed fields; use an owned type such as `String`",
+                        )
+                        .with_span(reference),
+                    );
+                }
+            }
+        }
+
+        if self.from_staging.is_present() && self.context.is_some() {
+            errors.push(
+                darling::Error::custom("`from_staging` cannot be used with `context`")
+                    .with_span(&self.from_staging.span()),
+            );
+        }
+
+        if self.try_finalize.is_present() {
+            for group in &self.at_least_one_of {
+                errors.push(
+                    darling::Error::custom("`at_least_one_of` cannot be used with `try_finalize`")
+                        .with_span(&group.error),
+                );
+            }
+        }
+
+        if self.data.is_enum() {
+            for group in &self.at_least_one_of {
+                errors.push(
+                    darling::Error::custom("`at_least_one_of` is not supported on enums")
+                        .with_span(&group.error),
+                );
+            }
+        }
+
+        for fields in self.field_sets() {
+            errors.handle(check_constraints(fields));
+        }
+
+        if let Data::Struct(fields) = &self.data {
+            for name in self.at_least_one_of.iter().flat_map(|group| &group.fields) {
+                let target = fields
+                    .iter()
+                    .find(|field| field.ident.as_ref() == Some(name));
+                let message = match target {
+                    None => format!("Unknown field `{}`", name),
+                    Some(target) if target.skip.is_some() => format!(
+                        "Field `{}` is skipped and cannot be used in `at_least_one_of`",
+                        name
+                    ),
+                    Some(_) => continue,
+                };
+                errors.push(darling::Error::custom(message).with_span(name));
+            }
+        }
+
+        if self.data.is_enum() && self.setters.is_present() {
+            errors.push(
+                darling::Error::custom("`setters` is not supported on enums")
+                    .with_span(&self.setters.span()),
+            );
+        }
+
+        if self.data.is_enum() && self.apply_to.is_present() {
+            errors.push(
+                darling::Error::custom("`apply_to` is not supported on enums")
+                    .with_span(&self.apply_to.span()),
+            );
+        }
+
+        if self.data.is_enum() && self.as_ref.is_present() {
+            errors.push(
+                darling::Error::custom("`as_ref` is not supported on enums")
+                    .with_span(&self.as_ref.span()),
+            );
+        }
+
+        if self.data.is_enum() && self.patch.is_some() {
+            errors.push(
+                darling::Error::custom("`patch` is not supported on enums").with_span(&self.ident),
+            );
+        }
+
+        for (option, value) in [("prefix", &self.prefix), ("suffix", &self.suffix)] {
+            if let Some(value) = value
+                && !value.chars().all(|c| c == '_' || c.is_alphanumeric())
+            {
+                errors.push(
+                    darling::Error::custom(format!(
+                        "`{}` can only contain letters, digits and underscores",
+                        option
+                    ))
+                    .with_span(&self.ident),
+                );
+            }
+        }
+
+        if let Some(name) = &self.name {
+            for (option, value) in [("prefix", &self.prefix), ("suffix", &self.suffix)] {
+                if value.is_some() {
+                    errors.push(
+                        darling::Error::custom(format!("`{}` cannot be used with `name`", option))
+                            .with_span(name),
+                    );
+                }
+            }
+        }
+
+        if self.non_exhaustive.is_present()
+            && !self.data.is_enum()
+            && self.builder.is_none()
+            && self.missing.is_none()
+            && !self.from_target.is_present()
+        {
+            errors.push(
+                darling::Error::custom(
+                    "`non_exhaustive` requires `builder`, `missing` or `from_target`, so other crates can create the checker",
+                )
+                .with_span(&self.non_exhaustive.span()),
+            );
+        }
+
+        if let Some(remote) = &self.remote
+            && self.patch.is_some()
+        {
+            errors.push(
+                darling::Error::custom("`patch` cannot be used with `remote`").with_span(remote),
+            );
+        }
+
+        if self.data.is_enum() && self.merge.is_some() {
+            errors.push(
+                darling::Error::custom("`merge` is not supported on enums").with_span(&self.ident),
+            );
+        }
+
+        if self.data.is_enum() && self.accessors.is_present() {
+            errors.push(
+                darling::Error::custom("`accessors` is not supported on enums")
+                    .with_span(&self.accessors.span()),
+            );
+        }
+
+        if self.data.is_enum()
+            && let Some(builder) = &self.builder
+        {
+            errors.push(
+                darling::Error::custom("`builder` is not supported on enums").with_span(builder),
+            );
+        }
+
+        if let Some(missing) = &self.missing {
+            if self.data.is_enum() {
+                errors.push(
+                    darling::Error::custom("`missing` is not supported on enums")
+                        .with_span(missing),
+                );
+            }
+
+            if self.builder.is_some() {
+                errors.push(
+                    darling::Error::custom("`builder` and `missing` cannot be used together")
+                        .with_span(missing),
+                );
+            }
+        }
+
+        for field in self.fields() {
+            errors.handle(field.validate());
+
+            if self.data.is_enum()
+                && let Some(validate_async) = &field.validate_async
+            {
+                errors.push(
+                    darling::Error::custom("`validate_async` is not supported on enums")
+                        .with_span(validate_async),
+                );
+            }
+
+            if self.try_finalize.is_present() {
+                for option in field.finalize_options() {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "`{}` cannot be used with `try_finalize`",
+                            option
+                        ))
+                        .with_span(&field.span()),
+                    );
+                }
+            }
+
+            if let Some(error) = &field.error {
+                let option = if self.validate_method.is_present() {
+                    Some("validate")
+                } else if self.inspect.is_present() {
+                    Some("inspect")
+                } else {
+                    None
+                };
+                if let Some(option) = option {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "`error` cannot be used on fields when the struct has `{}`",
+                            option
+                        ))
+                        .with_span(error),
+                    );
+                }
+            }
+
+            if let Some(param) = self.error_param() {
+                let mut options = vec![];
+                if field.error.is_some() {
+                    options.push("error");
+                }
+                if field.spanned.is_present() {
+                    options.push("spanned");
+                }
+                if field.nested.is_some() && !field.merges_errors() {
+                    options.push("nested");
+                }
+                if !field.requires.is_empty() {
+                    options.push("requires");
+                }
+                if !field.conflicts_with.is_empty() {
+                    options.push("conflicts_with");
+                }
+                for option in options {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "`{}` cannot be used on fields when the struct has `generic_error`",
+                            option
+                        ))
+                        .with_span(&field.span()),
+                    );
+                }
+
+                if field.elements == Some(Override::Inherit) {
+                    errors.push(
+                        darling::Error::custom(
+                            "`elements` needs a function that attaches the index, e.g. `elements = path`, when the struct has `generic_error`",
+                        )
+                        .with_span(&field.span()),
+                    );
+                }
+
+                if let Some(Nested {
+                    checker: None,
+                    merge_errors,
+                }) = &field.nested
+                    && merge_errors.is_present()
+                {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "`nested(merge_errors)` needs the field's checker type, e.g. `nested(checker = {}Staging::<{}>, merge_errors)`, when the struct has `generic_error`",
+                            field.ty.to_token_stream(),
+                            param
+                        ))
+                        .with_span(&field.span()),
+                    );
+                }
+            }
+
+            if self.from_target.is_present()
+                && let Some(with) = &field.with
+            {
+                errors.push(
+                    darling::Error::custom("`with` cannot be used with `from_target`")
+                        .with_span(with),
+                );
+            }
+
+            if self.apply_to.is_present()
+                && let Some(with) = &field.with
+            {
+                errors.push(
+                    darling::Error::custom("`with` cannot be used with `apply_to`").with_span(with),
+                );
+            }
+
+            if field.into.is_present() && !self.has_setters() {
+                errors.push(
+                    darling::Error::custom("`into` requires `setters` or `builder` on the struct")
+                        .with_span(&field.into.span()),
+                );
+            }
+
+            if self.data.is_enum()
+                && let Some(vis) = &field.checker_vis
+            {
+                errors.push(
+                    darling::Error::custom("`vis` is not supported on enum variant fields")
+                        .with_span(vis),
+                );
+            }
+        }
+
+        errors.finish_with(self)
+    }
+
+    /// All fields of the receiver, including those in every enum variant.
+    fn fields(&self) -> Box<dyn Iterator<Item = &InputField> + '_> {
+        match &self.data {
+            Data::Struct(fields) => Box::new(fields.iter()),
+            Data::Enum(variants) => Box::new(variants.iter().flat_map(|v| v.fields.iter())),
+        }
+    }
+
+    /// Each group of fields that is finalized together: the struct's fields, or the
+    /// fields of each enum variant.
+    fn field_sets(&self) -> Vec<&Fields<InputField>> {
+        match &self.data {
+            Data::Struct(fields) => vec![fields],
+            Data::Enum(variants) => variants.iter().map(|v| &v.fields).collect(),
+        }
+    }
+
+    /// Whether any field has a `group`, in which case finalization tracks the group of
+    /// every error.
+    fn has_groups(&self) -> bool {
+        self.fields().any(|field| field.group.is_some())
+    }
+
+    /// Whether the checker has an `errors(&self)` method, which `validate` is built on.
+    fn has_errors_method(&self) -> bool {
+        self.validate_method.is_present() || self.inspect.is_present()
+    }
+
+    /// Whether the checker has `set_<field>` methods, which `builder` implies.
+    fn has_setters(&self) -> bool {
+        self.setters.is_present() || self.builder.is_some()
+    }
+
+    /// Path of the type the checker finalizes into: the `remote` type if set, otherwise
+    /// the deriving type.
+    fn target(&self) -> Cow<'_, Path> {
+        self.remote
+            .as_ref()
+            .map(Cow::Borrowed)
+            .unwrap_or_else(|| Cow::Owned(self.ident.clone().into()))
+    }
+
+    /// First line of the checker's documentation, which is followed by the deriving
+    /// type's own.
+    fn checker_doc(&self) -> String {
+        let target = self.target();
+        let target = target
+            .segments
+            .iter()
+            .map(|segment| segment.ident.unraw().to_string())
+            .collect::<Vec<_>>()
+            .join("::");
+        let fields = if self.data.is_enum() {
+            "each field of each variant"
+        } else {
+            "each field"
+        };
+        format!(
+            " Staging form of [`{}`], in which {} holds either its value or the error found while staging it.",
+            target, fields
+        )
+    }
+
+    /// `#[automatically_derived]`, for each trait impl, if `automatically_derived` is set.
+    fn automatically_derived(&self) -> Option<TokenStream> {
+        self.automatically_derived
+            .is_present()
+            .then(|| quote!(#[automatically_derived]))
+    }
+
+    /// `#[doc(hidden)]`, for the checker and each other generated type, if `doc_hidden`
+    /// is set.
+    fn doc_hidden(&self) -> Option<TokenStream> {
+        self.doc_hidden.is_present().then(|| quote!(#[doc(hidden)]))
+    }
+
+    /// Visibility of the checker and its methods.
+    fn checker_vis(&self) -> &syn::Visibility {
+        self.checker_vis.as_ref().unwrap_or(&self.vis)
+    }
+
+    /// Whether `vis` may make the checker less visible than the deriving type, so that
+    /// the type's trait impls cannot name it as an associated type.
+    fn hides_checker(&self) -> bool {
+        self.checker_vis.as_ref().is_some_and(|vis| {
+            !matches!(vis, syn::Visibility::Public(_))
+                && vis.to_token_stream().to_string() != self.vis.to_token_stream().to_string()
+        })
+    }
+
+    pub fn checker_name(&self) -> Ident {
+        self.name.clone().unwrap_or_else(|| {
+            let prefix = self.prefix.as_ref().unwrap_or(&self.naming.prefix);
+            let suffix = self.suffix.as_ref().unwrap_or(&self.naming.suffix);
+            format_ident!(
+                "{}{}{}",
+                prefix,
+                self.ident,
+                suffix,
+                span = self.ident.span()
+            )
+        })
+    }
+
+    /// Error type stored in each checker field.
+    fn error(&self) -> &Path {
+        self.error
+            .as_ref()
+            .expect("`error` is set during validation")
+    }
+
+    /// Name of the checker's error type parameter, if it has one.
+    fn error_param(&self) -> Option<Ident> {
+        Some(match self.generic_error.as_ref()? {
+            Override::Explicit(name) => name.clone(),
+            Override::Inherit => format_ident!("E", span = self.ident.span()),
+        })
+    }
+
+    /// Generics of the checker: those of the target, with the error type parameter added
+    /// if `generic_error` is set.
+    fn checker_generics(&self) -> Cow<'_, syn::Generics> {
+        match self.error_param() {
+            Some(param) => {
+                let mut generics = self.generics.clone();
+                generics.params.push(parse_quote!(#param));
+                Cow::Owned(generics)
+            }
+            None => Cow::Borrowed(&self.generics),
+        }
+    }
+
+    /// With `generic_error`, the bound the `TryFrom` impls need to build the final error
+    /// from the collected errors.
+    fn final_error_bound(&self) -> Option<syn::WherePredicate> {
+        self.generic_error.as_ref()?;
+
+        let root = self.crate_root();
+        let final_error = self.final_error();
+        let collected = self.collected_error();
+        if self.fail_fast.is_present() {
+            Some(parse_quote!(#final_error: #root::export::From<#collected>))
+        } else if self.combine.is_some() {
+            None
+        } else if self.non_empty.is_present() {
+            Some(parse_quote! {
+                #final_error: #root::export::From<#root::export::AtLeastOne<#collected>>
+            })
+        } else {
+            Some(parse_quote!(#final_error: #root::export::FromIterator<#collected>))
+        }
+    }
+
+    pub fn final_error(&self) -> &Path {
+        self.final_error.as_ref().unwrap_or(self.error())
+    }
+
+    /// Extra parameter, with its leading comma, taken by each method that finalizes the
+    /// checker if `context` is set.
+    fn context_param(&self) -> Option<TokenStream> {
+        let context = self.context.as_ref()?;
+        Some(quote!(, ctx: &#context))
+    }
+
+    /// Extra argument passed to a validator or a finalizing method, if `context` is set.
+    fn context_arg(&self) -> Option<TokenStream> {
+        self.context.as_ref().map(|_| quote!(ctx))
+    }
+
+    /// Expression that finalizes `checker` into the target, or the final error.
+    ///
+    /// This is the body of the `TryFrom` and `FromStaging` impls, of `try_into_with` when
+    /// `context` is set, or of `finalize` when `no_try_from` is set.
+    fn try_from_body(&self, checker: TokenStream) -> TokenStream {
+        let root = self.crate_root();
+        let ctx = self.context_arg();
+        match &self.combine {
+            _ if self.fail_fast.is_present() => {
+                quote!(#checker.__finalize_first(#ctx).map_err(#root::export::Into::into))
+            }
+            Some(combine) => quote!(#checker.into_result(#ctx).map_err(#combine)),
+            None if self.non_empty.is_present() => {
+                quote!(#checker.__finalize_non_empty(#ctx).map_err(#root::export::Into::into))
+            }
+            None => quote!(
+                #checker
+                    .into_result(#ctx)
+                    .map_err(|errors| errors.into_iter().collect())
+            ),
+        }
+    }
+
+    /// Expression that finalizes `self` with its `TryFrom` impl, with `try_into_with`
+    /// when `context` is set, or with `finalize` when `no_try_from` is set.
+    fn finalize_self(&self) -> TokenStream {
+        let root = self.crate_root();
+        let ident = self.target();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        match self.context_arg() {
+            Some(ctx) => quote!(self.try_into_with(#ctx)),
+            None if self.no_try_from.is_present() => quote!(self.finalize()),
+            None => quote!(<#ident #ty_generics as #root::export::TryFrom<Self>>::try_from(self)),
+        }
+    }
+
+    /// Type of each error returned by `into_result`: the struct's error type, wrapped in
+    /// a `FieldError` if `field_errors` is set.
+    fn collected_error(&self) -> syn::Type {
+        let root = self.crate_root();
+        let error = self.error();
+        if self.field_errors.is_present() {
+            parse_quote!(#root::export::FieldError<#error>)
+        } else {
+            parse_quote!(#error)
+        }
+    }
+
+    /// Type of the list of errors returned by `into_result`.
+    fn collected_errors(&self) -> syn::Type {
+        match &self.error_storage {
+            Some(ErrorStorage { container, .. }) => parse_quote!(#container),
+            None => {
+                let root = self.crate_root();
+                let error = self.collected_error();
+                parse_quote!(#root::export::Vec<#error>)
+            }
+        }
+    }
+
+    /// Statement that records `err` in `__errors`.
+    ///
+    /// With `error_storage(overflow = expr)`, an error that does not fit replaces the last
+    /// one with `expr`, so a full list always ends with the overflow error.
+    fn push_error(&self, err: TokenStream) -> TokenStream {
+        let root = self.crate_root();
+        match &self.error_storage {
+            Some(ErrorStorage {
+                overflow: Some(overflow),
+                ..
+            }) => quote! {
+                if __errors.push(#err).is_err() {
+                    let _ = __errors.pop();
+                    let _ = __errors.push(#root::export::Into::into(#overflow));
+                }
+            },
+            Some(_) => quote!(__errors.extend(#root::export::Some(#err));),
+            None => quote!(__errors.push(#err);),
+        }
+    }
+
+    /// Statement that records each error yielded by `errors` in `__errors`.
+    fn extend_errors(&self, errors: TokenStream) -> TokenStream {
+        if self.has_fixed_storage() {
+            let push = self.push_error(quote!(__error));
+            quote! {
+                for __error in #errors {
+                    #push
+                }
+            }
+        } else {
+            quote!(__errors.extend(#errors);)
+        }
+    }
+
+    /// Statement that records each error yielded by `errors`, or returns the first of them
+    /// with `Finalize::FirstError`.
+    fn record_errors(&self, errors: TokenStream, mode: Finalize) -> TokenStream {
+        if mode != Finalize::FirstError {
+            return self.extend_errors(errors);
+        }
+
+        let root = self.crate_root();
+        quote! {
+            if let #root::export::Some(err) = #root::export::Iterator::next(
+                &mut #root::export::IntoIterator::into_iter(#errors),
+            ) {
+                return #root::export::Err(err);
+            }
+        }
+    }
+
+    /// Whether `error_storage` has a fixed capacity, set by giving it an `overflow` error.
+    fn has_fixed_storage(&self) -> bool {
+        self.error_storage
+            .as_ref()
+            .is_some_and(|error_storage| error_storage.overflow.is_some())
+    }
+
+    pub fn crate_root<'a>(&'a self) -> Cow<'a, Path> {
+        self.crate_root
+            .as_ref()
+            .map(Cow::Borrowed)
+            .unwrap_or_else(|| Cow::Owned(parse_quote!(::staging_core)))
+    }
+
+    fn style(&self) -> Style {
+        match &self.data {
+            Data::Struct(fields) => fields.style,
+            Data::Enum(_) => unreachable!("additional_errors is only supported on structs"),
+        }
+    }
+
+    /// Number of fields that appear in the checker struct.
+    fn field_count(&self) -> usize {
+        match &self.data {
+            Data::Struct(fields) => fields.iter().filter(|f| f.skip.is_none()).count(),
+            Data::Enum(_) => unreachable!("additional_errors is only supported on structs"),
+        }
+    }
+
+    fn additional_errors_ident(&self) -> Option<Ident> {
+        let additional_errors = self.additional_errors.as_ref()?;
+        Some(
+            additional_errors
+                .name
+                .clone()
+                .unwrap_or_else(|| Ident::new("additional_errors", additional_errors.span())),
+        )
+    }
+
+    /// Visibility of the `additional_errors` list on the checker.
+    fn additional_errors_vis(&self) -> Cow<'_, syn::Visibility> {
+        self.additional_errors
+            .as_ref()
+            .and_then(|additional_errors| additional_errors.vis.as_ref())
+            .map(Cow::Borrowed)
+            .unwrap_or_else(|| Cow::Owned(parse_quote!(pub)))
+    }
+
+    /// Type of the `additional_errors` list on the checker.
+    fn additional_errors_type(&self) -> syn::Type {
+        match self
+            .additional_errors
+            .as_ref()
+            .and_then(|additional_errors| additional_errors.container.as_ref())
+        {
+            Some(container) => parse_quote!(#container),
+            None => {
+                let root = self.crate_root();
+                let error = self.error();
+                parse_quote!(#root::export::Vec<#error>)
+            }
+        }
+    }
+
+    /// The member used to access the `additional_errors` list on the checker.
+    ///
+    /// For tuple structs, the list is stored after all the positional fields.
+    fn additional_errors_member(&self) -> Option<Member> {
+        let ident = self.additional_errors_ident()?;
+        Some(if self.style().is_tuple() {
+            Member::Unnamed(syn::Index {
+                index: self.field_count() as u32,
+                span: ident.span(),
+            })
+        } else {
+            Member::Named(ident)
+        })
+    }
+
+    /// Closure that is true for an error severe enough to fail finalization under
+    /// `threshold`.
+    fn is_severe(&self) -> Option<TokenStream> {
+        let threshold = self.threshold.as_ref()?;
+        let root = self.crate_root();
+        let error = self.error();
+        Some(quote! {
+            |error: &#error| #root::export::Severity::severity(error) >= #threshold
+        })
+    }
+
+    /// The member used to access the `warnings` list on the checker.
+    ///
+    /// For tuple structs, the list is stored after all the positional fields and the
+    /// `additional_errors` list.
+    fn warnings_member(&self) -> Option<Member> {
+        let warnings = self.warnings.as_ref()?;
+        Some(if self.style().is_tuple() {
+            let index = self.field_count() + usize::from(self.additional_errors.is_some());
+            Member::Unnamed(syn::Index {
+                index: index as u32,
+                span: warnings.span(),
+            })
+        } else {
+            Member::Named(Ident::new("warnings", warnings.span()))
+        })
+    }
+
+    /// Declarations of the `extra` fields, each given the checker's `field_vis` unless it
+    /// sets its own visibility, and a doc comment unless it has one.
+    fn extra_decls(&self) -> Vec<syn::Field> {
+        self.extra
+            .0
+            .iter()
+            .map(|field| {
+                let mut field = field.clone();
+                if let syn::Visibility::Inherited = field.vis {
+                    field.vis = self.field_vis.clone().unwrap_or_else(|| parse_quote!(pub));
+                }
+                if !field.attrs.iter().any(|attr| attr.path().is_ident("doc")) {
+                    field.attrs.insert(
+                        0,
+                        parse_quote!(#[doc = " Kept on the checker only, and dropped when it is finalized."]),
+                    );
+                }
+                field
+            })
+            .collect()
+    }
+
+    /// The member used to access each `extra` field on the checker.
+    fn extra_members(&self) -> Vec<Member> {
+        self.extra
+            .0
+            .iter()
+            .filter_map(|field| field.ident.clone().map(Member::Named))
+            .collect()
+    }
+
+    fn receiver_fields<'a>(&'a self, fields: &'a Fields<InputField>) -> Vec<ReceiverField<'a>> {
+        let mut checker_index = 0;
+        fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let receiver_field = ReceiverField {
+                    receiver: self,
+                    field,
+                    index,
+                    checker_index,
+                };
+                if field.skip.is_none() {
+                    checker_index += 1;
+                }
+                receiver_field
+            })
+            .collect()
+    }
+
+    fn inherent_methods(&self) -> Vec<syn::ImplItemFn> {
+        let root = self.crate_root();
+        let mut methods = Vec::new();
+
+        let ident = self.target();
+        let error = self.collected_error();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let ctx_param = self.context_param();
+        let ctx = self.context_arg();
+        if self.has_groups() {
+            let finalize_body = self.finalize_body(Finalize::Grouped);
+            methods.push(parse_quote! {
+                /// Finalize the checker, returning every error that was found as a `Vec`
+                /// rather than combining them into the final error type.
+                ///
+                /// This backs the `TryFrom` impl and lets parent checkers merge this checker's
+                /// errors into their own.
+                pub fn into_result(self #ctx_param) -> #root::export::Result<#ident #ty_generics, #root::export::Vec<#error>> {
+                    self.__finalize_grouped(#ctx)
+                        .map_err(|errors| errors.into_iter().map(|(_, error)| error).collect())
+                }
+            });
+
+            methods.push(parse_quote! {
+                /// Finalize the checker, returning every error that was found along with
+                /// the group of the field it came from.
+                #[doc(hidden)]
+                pub fn __finalize_grouped(self #ctx_param) -> #root::export::Result<
+                    #ident #ty_generics,
+                    #root::export::Vec<(#root::export::Option<&'static str>, #error)>,
+                > {
+                    #finalize_body
+                }
+            });
+
+            methods.push(parse_quote! {
+                /// Finalize the checker, returning the errors grouped by the `group` of the
+                /// field they came from, in the order each group was first seen.
+                ///
+                /// Errors from fields without a group, and errors that are not tied to a
+                /// single field, are grouped under `None`.
+                pub fn errors_by_group(self #ctx_param) -> #root::export::Result<
+                    #ident #ty_generics,
+                    #root::export::Vec<(#root::export::Option<&'static str>, #root::export::Vec<#error>)>,
+                > {
+                    self.__finalize_grouped(#ctx).map_err(|errors| {
+                        let mut groups: #root::export::Vec<(_, #root::export::Vec<#error>)> =
+                            #root::export::Vec::new();
+                        for (group, error) in errors {
+                            match groups.iter_mut().find(|(existing, _)| *existing == group) {
+                                #root::export::Some((_, errors)) => errors.push(error),
+                                #root::export::None => groups.push((group, #root::export::Vec::from([error]))),
+                            }
+                        }
+                        groups
+                    })
+                }
+            });
+        } else if self.non_empty.is_present() {
+            methods.push(parse_quote! {
+                /// Finalize the checker, returning every error that was found rather than
+                /// combining them into the final error type.
+                ///
+                /// This lets parent checkers merge this checker's errors into their own.
+                pub fn into_result(self #ctx_param) -> #root::export::Result<#ident #ty_generics, #root::export::Vec<#error>> {
+                    self.__finalize_non_empty(#ctx).map_err(#root::export::AtLeastOne::into_vec)
+                }
+            });
+        } else {
+            let finalize_body = self.finalize_body(Finalize::All);
+            let errors = self.collected_errors();
+            methods.push(parse_quote! {
+                /// Finalize the checker, returning every error that was found rather than
+                /// combining them into the final error type.
+                ///
+                /// This backs the `TryFrom` impl and lets parent checkers merge this checker's
+                /// errors into their own.
+                pub fn into_result(self #ctx_param) -> #root::export::Result<#ident #ty_generics, #errors> {
+                    #finalize_body
+                }
+            });
+        }
+
+        // A parent that sets `fail_fast` or `non_empty` finalizes its `nested(merge_errors)` fields with
+        // the matching method, so they must set it too.
+        if self.fail_fast.is_present() {
+            let finalize_body = self.finalize_body(Finalize::FirstError);
+            methods.push(parse_quote! {
+                /// Finalize the checker, stopping at the first error that is found.
+                ///
+                /// This backs the `TryFrom` impl when `fail_fast` is set.
+                #[doc(hidden)]
+                pub fn __finalize_first(self #ctx_param) -> #root::export::Result<#ident #ty_generics, #error> {
+                    #finalize_body
+                }
+            });
+        }
+
+        if self.non_empty.is_present() {
+            let finalize_body = self.finalize_body(Finalize::NonEmpty);
+            methods.push(parse_quote! {
+                /// Finalize the checker, returning every error that was found in a list
+                /// that cannot be empty.
+                ///
+                /// This backs the `TryFrom` impl when `non_empty` is set.
+                #[doc(hidden)]
+                pub fn __finalize_non_empty(self #ctx_param) -> #root::export::Result<
+                    #ident #ty_generics,
+                    #root::export::AtLeastOne<#error>,
+                > {
+                    #finalize_body
+                }
+            });
+        }
+
+        if let Some(context) = &self.context {
+            let final_error = self.final_error();
+            let where_clause = self.final_error_bound().map(|bound| quote!(where #bound));
+            let body = self.try_from_body(quote!(self));
+            methods.push(parse_quote! {
+                /// Finalize the checker, passing `ctx` to each validator, and combine any
+                /// errors into the final error type.
+                pub fn try_into_with(
+                    self,
+                    ctx: &#context,
+                ) -> #root::export::Result<#ident #ty_generics, #final_error> #where_clause {
+                    #body
+                }
+            });
+        }
+
+        if self.no_try_from.is_present() {
+            let final_error = self.final_error();
+            let where_clause = self.final_error_bound().map(|bound| quote!(where #bound));
+            let body = self.try_from_body(quote!(self));
+            methods.push(parse_quote! {
+                /// Finalize the checker, combining any errors into the final error type.
+                pub fn finalize(self) -> #root::export::Result<#ident #ty_generics, #final_error> #where_clause {
+                    #body
+                }
+            });
+        }
+
+        if let Some(member) = self.additional_errors_member() {
+            let error = self.error();
+
+            let method: syn::ImplItemFn = parse_quote! {
+                /// Handle a result, pushing any errors into the `additional_errors` list.
+                pub fn handle<Val>(
+                    &mut self,
+                    result: #root::export::Result<Val, impl #root::export::Into<#error>>
+                ) -> #root::export::Option<Val> {
+                    match result {
+                        #root::export::Ok(value) => #root::export::Some(value),
+                        #root::export::Err(err) => {
+                            #root::export::Extend::extend(
+                                &mut self.#member,
+                                #root::export::Some(err.into()),
+                            );
+                            #root::export::None
+                        }
+                    }
+                }
+            };
+            methods.push(method);
+        }
+
+        if let Some(warning) = &self.warnings
+            && let Some(member) = self.warnings_member()
+        {
+            let final_error = self.final_error();
+            let where_clause = self.final_error_bound().map(|bound| quote!(where #bound));
+            let finalize = if self.threshold.is_some() {
+                self.try_from_body(quote!(self))
+            } else {
+                self.finalize_self()
+            };
+            let pass_errors = self.is_severe().zip(self.additional_errors_member()).map(
+                |(is_severe, errors_member)| {
+                    let errors_ty = self.additional_errors_type();
+                    let error = self.error();
+                    quote! {
+                        let (__severe, __passed): (#errors_ty, #root::export::Vec<#error>) =
+                            #root::export::Iterator::partition(
+                                #root::export::IntoIterator::into_iter(
+                                    #root::export::mem::take(&mut self.#errors_member),
+                                ),
+                                #is_severe,
+                            );
+                        self.#errors_member = __severe;
+                        self.#member
+                            .extend(__passed.into_iter().map(#root::export::Into::into));
+                    }
+                },
+            );
+
+            methods.push(parse_quote! {
+                /// Record a warning, which does not stop the checker from finalizing.
+                pub fn warn(&mut self, warning: impl #root::export::Into<#warning>) -> &mut Self {
+                    self.#member.push(warning.into());
+                    self
+                }
+            });
+            methods.push(parse_quote! {
+                /// Finalize the checker, returning the target along with every warning that
+                /// was recorded.
+                pub fn finalize_with_warnings(
+                    mut self
+                    #ctx_param
+                ) -> #root::export::Result<
+                    (#ident #ty_generics, #root::export::Vec<#warning>),
+                    #final_error,
+                > #where_clause {
+                    #pass_errors
+                    let warnings = #root::export::mem::take(&mut self.#member);
+                    #finalize.map(|value| (value, warnings))
+                }
+            });
+        }
+
+        if (self.builder.is_some() || self.missing.is_some())
+            && let Data::Struct(fields) = &self.data
+        {
+            let fields = self.receiver_fields(fields);
+            let inits = fields.iter().filter_map(ReceiverField::initial_value);
+            let lists = self
+                .additional_errors_member()
+                .into_iter()
+                .chain(self.warnings_member())
+                .chain(self.extra_members())
+                .map(|member| quote!(#member: #root::export::Default::default()));
+
+            methods.push(parse_quote! {
+                /// Create a checker in which every field that needs a value holds an error
+                /// recording that it is missing.
+                pub fn new() -> Self {
+                    Self {
+                        #(#inits,)*
+                        #(#lists,)*
+                    }
+                }
+            });
+        }
+
+        if self.has_errors_method() {
+            let error = self.error();
+            let body = self.borrow_body(ReceiverField::borrow_errors, true);
+            methods.push(parse_quote! {
+                /// Every error the checker currently holds, in the order of the fields and
+                /// followed by the additional errors.
+                pub fn errors(&self) -> impl #root::export::Iterator<Item = &#error> {
+                    let mut __errors: #root::export::Vec<&#error> = #root::export::Vec::new();
+                    #body
+                    __errors.into_iter()
+                }
+            });
+        }
+
+        if self.validate_method.is_present() {
+            let error = self.error();
+            methods.push(parse_quote! {
+                /// Borrow every error the checker currently holds, without finalizing it.
+                pub fn validate(&self) -> #root::export::Result<(), #root::export::Vec<&#error>> {
+                    let __errors: #root::export::Vec<&#error> = self.errors().collect();
+
+                    if __errors.is_empty() {
+                        #root::export::Ok(())
+                    } else {
+                        #root::export::Err(__errors)
+                    }
+                }
+            });
+        }
+
+        if self.inspect.is_present() {
+            let body = self.has_errors_body(false);
+            methods.push(parse_quote! {
+                /// Whether any field, or the list of additional errors, holds an error that
+                /// finalizing would report.
+                #[doc(hidden)]
+                pub fn __has_errors(&self) -> bool {
+                    #body
+                }
+            });
+        }
+
+        if self.inspect.is_present() {
+            let error = self.error();
+            // Additional errors below the `threshold` are passed on as warnings rather than
+            // failing finalization, so they are not counted, as in `is_complete`.
+            let body = if self.threshold.is_some() {
+                self.has_errors_body(true)
+            } else {
+                quote!(self.errors().next().is_some())
+            };
+            methods.push(parse_quote! {
+                /// Whether the checker holds any error, other than additional errors below
+                /// the `threshold` when one is set.
+                pub fn has_errors(&self) -> bool {
+                    #body
+                }
+            });
+
+            methods.push(parse_quote! {
+                /// The error held by the earliest field, or the first additional error if
+                /// no field holds one.
+                pub fn first_error(&self) -> #root::export::Option<&#error> {
+                    self.errors().next()
+                }
+            });
+
+            let body = self.borrow_body(ReceiverField::push_status, false);
+            methods.push(parse_quote! {
+                /// The name of each field that is not skipped, along with the first error
+                /// it holds, in the order the fields are declared.
+                pub fn field_statuses(
+                    &self,
+                ) -> impl #root::export::Iterator<
+                    Item = (&'static str, #root::export::Result<(), &#error>),
+                > {
+                    let mut __statuses: #root::export::Vec<(
+                        &'static str,
+                        #root::export::Result<(), &#error>,
+                    )> = #root::export::Vec::new();
+                    #body
+                    __statuses.into_iter()
+                }
+            });
+
+            methods.push(parse_quote! {
+                /// Number of errors the checker holds.
+                pub fn error_count(&self) -> usize {
+                    self.errors().count()
+                }
+            });
+
+            methods.push(parse_quote! {
+                /// Whether the checker holds no error that finalizing would report.
+                ///
+                /// Errors in fields with a `default` are not counted, and checks that only
+                /// run during finalization are not made.
+                pub fn is_complete(&self) -> bool {
+                    !self.__has_errors()
+                }
+            });
+        }
+
+        if self.try_finalize.is_present() {
+            // Nothing that `try_finalize` allows reads the context, but it is taken like
+            // every other method that finalizes the checker.
+            let ctx_param = self
+                .context
+                .as_ref()
+                .map(|context| quote!(, _ctx: &#context));
+            methods.push(parse_quote! {
+                /// Finalize the checker if it holds no errors, or return it unchanged so it
+                /// can be fixed and finalized again.
+                pub fn try_finalize(self #ctx_param) -> #root::export::Result<#ident #ty_generics, Self> {
+                    #root::export::Result::map(
+                        <Self as #root::export::TakeValues>::take_values(self),
+                        <Self as #root::export::TakeValues>::build,
+                    )
+                }
+            });
+        }
+
+        if let Data::Struct(fields) = &self.data {
+            let checks: Vec<_> = self
+                .receiver_fields(fields)
+                .iter()
+                .filter_map(ReceiverField::validate_async)
+                .collect();
+
+            if !checks.is_empty() {
+                let final_error = self.final_error();
+                let finalize = self.finalize_self();
+                methods.push(parse_quote! {
+                    /// Await each field's `validate_async` function, recording any error it
+                    /// returns in the field, then finalize the checker, returning every error
+                    /// that was found.
+                    pub async fn try_finalize_async(
+                        mut self #ctx_param
+                    ) -> #root::export::Result<#ident #ty_generics, #final_error> {
+                        #(#checks)*
+                        #finalize
+                    }
+                });
+            }
+        }
+
+        if self.builder.is_some() {
+            let final_error = self.final_error();
+            let finalize = self.finalize_self();
+            methods.push(parse_quote! {
+                /// Finalize the builder, returning every error that was found.
+                pub fn build(self #ctx_param) -> #root::export::Result<#ident #ty_generics, #final_error> {
+                    #finalize
+                }
+            });
+        }
+
+        if self.has_setters()
+            && let Data::Struct(fields) = &self.data
+        {
+            for field in self.receiver_fields(fields) {
+                methods.extend(field.setter());
+                methods.extend(field.try_setter());
+            }
+        }
+
+        if let Some(merge) = &self.merge
+            && let Data::Struct(fields) = &self.data
+        {
+            let options = merge.clone().unwrap_or_default();
+            let fields = self.receiver_fields(fields);
+            let values = fields
+                .iter()
+                .filter_map(|field| field.merged_value(options.prefer));
+            let (first, second) = match options.additional_errors {
+                Prefer::Current => (quote!(self), quote!(other)),
+                Prefer::Other => (quote!(other), quote!(self)),
+            };
+            let lists = self
+                .additional_errors_member()
+                .into_iter()
+                .chain(self.warnings_member())
+                .map(|member| {
+                    quote! {
+                        #member: {
+                            let mut list = #first.#member;
+                            list.extend(#second.#member);
+                            list
+                        }
+                    }
+                });
+            let preferred = match options.prefer {
+                Prefer::Current => quote!(self),
+                Prefer::Other => quote!(other),
+            };
+            let extras = self
+                .extra_members()
+                .into_iter()
+                .map(|member| quote!(#member: #preferred.#member));
+
+            methods.push(parse_quote! {
+                /// Combine this checker with `other` field by field, keeping a successful
+                /// value over an error and a provided value over a missing one.
+                pub fn merge(self, other: Self) -> Self {
+                    Self {
+                        #(#values,)*
+                        #(#lists,)*
+                        #(#extras,)*
+                    }
+                }
+            });
+        }
+
+        if self.apply_to.is_present()
+            && let Data::Struct(fields) = &self.data
+        {
+            let checker_name = self.checker_name();
+            let fields = self.receiver_fields(fields);
+            let additional_errors = self
+                .additional_errors_member()
+                .map(|member| (member, format_ident!("__additional_errors")));
+            let pattern = destructure(
+                &parse_quote!(#checker_name),
+                &fields,
+                additional_errors
+                    .as_ref()
+                    .map(|(member, binding)| (member, binding)),
+            );
+            let updates = fields.iter().filter_map(ReceiverField::apply_update);
+
+            methods.push(parse_quote! {
+                /// Write each successful value onto `target`, leaving the fields that hold
+                /// errors or were not provided unchanged.
+                pub fn apply_to(self, target: &mut #ident #ty_generics) {
+                    let #pattern = self;
+                    #(#updates)*
+                }
+            });
+        }
+
+        if self.as_ref.is_present()
+            && let Data::Struct(fields) = &self.data
+        {
+            let name = self.ref_name();
+            let fields = self.receiver_fields(fields);
+            let values = fields.iter().filter_map(|field| {
+                if field.is_skipped() {
+                    return None;
+                }
+
+                let cfg = field.cfg_attrs();
+                let member = field.checker_member();
+                let (_, value) = field.borrowed(None, false);
+                Some(quote!(#(#cfg)* #member: #value))
+            });
+            let generics = self.checker_generics();
+            let args = generics.params.iter().map(|param| match param {
+                syn::GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+                syn::GenericParam::Type(param) => param.ident.to_token_stream(),
+                syn::GenericParam::Const(param) => param.ident.to_token_stream(),
+            });
+
+            methods.push(parse_quote! {
+                /// Borrow each field of the checker.
+                pub fn as_ref(&self) -> #name<'_, #(#args),*> {
+                    #name {
+                        #(#values,)*
+                    }
+                }
+            });
+        }
+
+        if let Some(param) = self.error_param() {
+            let mapped = format_ident!("{}2", param);
+            let checker_name = self.checker_name();
+            let generics = self.checker_generics();
+            let args = generics.params.iter().map(|generic| match generic {
+                syn::GenericParam::Lifetime(generic) => generic.lifetime.to_token_stream(),
+                syn::GenericParam::Type(generic) if generic.ident == param => {
+                    mapped.to_token_stream()
+                }
+                syn::GenericParam::Type(generic) => generic.ident.to_token_stream(),
+                syn::GenericParam::Const(generic) => generic.ident.to_token_stream(),
+            });
+            let body = match &self.data {
+                Data::Struct(fields) => {
+                    let values = self
+                        .receiver_fields(fields)
+                        .into_iter()
+                        .filter_map(|field| {
+                            if field.is_skipped() {
+                                return None;
+                            }
+
+                            let cfg = field.cfg_attrs();
+                            let member = field.checker_member();
+                            let value = field.map_errors(quote!(self.#member));
+                            Some(quote!(#(#cfg)* #member: #value))
+                        });
+                    let additional_errors = self.additional_errors_member().into_iter().map(|member| {
+                        quote! {
+                            #member: #root::export::Iterator::collect(#root::export::Iterator::map(
+                                #root::export::IntoIterator::into_iter(self.#member),
+                                &f,
+                            ))
+                        }
+                    });
+                    let moved = self
+                        .warnings_member()
+                        .into_iter()
+                        .chain(self.extra_members())
+                        .map(|member| quote!(#member: self.#member));
+                    quote! {
+                        #checker_name {
+                            #(#values,)*
+                            #(#additional_errors,)*
+                            #(#moved,)*
+                        }
+                    }
+                }
+                Data::Enum(variants) => {
+                    let arms = variants.iter().map(|variant| {
+                        let path: Path = {
+                            let variant = &variant.ident;
+                            parse_quote!(#checker_name::#variant)
+                        };
+                        let fields = self.receiver_fields(&variant.fields);
+                        let pattern = destructure(&path, &fields, None);
+                        let values = fields.iter().filter_map(|field| {
+                            if field.is_skipped() {
+                                return None;
+                            }
+
+                            let cfg = field.cfg_attrs();
+                            let member = field.checker_member();
+                            let binding = field.binding();
+                            let value = field.map_errors(quote!(#binding));
+                            Some(quote!(#(#cfg)* #member: #value))
+                        });
+                        quote!(#pattern => #path { #(#values,)* })
+                    });
+                    quote! {
+                        match self {
+                            #(#arms,)*
+                        }
+                    }
+                }
+            };
+
+            methods.push(parse_quote! {
+                /// Convert every error the checker holds with `f`, keeping each value and
+                /// where each error was found.
+                pub fn map_errors<#mapped>(
+                    self,
+                    f: impl #root::export::Fn(#param) -> #mapped,
+                ) -> #checker_name<#(#args),*> {
+                    #body
+                }
+            });
+        }
+
+        if self.accessors.is_present()
+            && let Data::Struct(fields) = &self.data
+        {
+            for field in self.receiver_fields(fields) {
+                methods.extend(field.accessor(false));
+                methods.extend(field.accessor(true));
+            }
+        }
+
+        methods
+    }
+
+    /// Declaration of the checker struct or enum.
+    fn checker_decl(&self) -> TokenStream {
+        let Self { data, .. } = self;
+        let vis = self.checker_vis();
+        let checker_name = self.checker_name();
+        let generics = self.checker_generics();
+        let where_clause = &generics.where_clause;
+
+        match data {
+            Data::Struct(fields) => {
+                let root = self.crate_root();
+                let errors_vis = self.additional_errors_vis();
+                let errors_ty = self.additional_errors_type();
+                let errors_decl: Option<syn::Field> = self.additional_errors_ident().map(|ident| {
+                    if fields.style.is_tuple() {
+                        parse_quote! {
+                            /// Errors that are not tied to a single field.
+                            #errors_vis #errors_ty
+                        }
+                    } else {
+                        parse_quote! {
+                            /// Errors that are not tied to a single field.
+                            #errors_vis #ident: #errors_ty
+                        }
+                    }
+                });
+
+                let warnings_decl: Option<syn::Field> = self.warnings.as_ref().map(|warning| {
+                    if fields.style.is_tuple() {
+                        parse_quote! {
+                            /// Issues that do not prevent finalizing.
+                            pub #root::export::Vec<#warning>
+                        }
+                    } else {
+                        parse_quote! {
+                            /// Issues that do not prevent finalizing.
+                            pub warnings: #root::export::Vec<#warning>
+                        }
+                    }
+                });
+
+                let field_decls = self
+                    .receiver_fields(fields)
+                    .iter()
+                    .filter_map(ReceiverField::field_decl)
+                    .chain(errors_decl)
+                    .chain(warnings_decl)
+                    .chain(self.extra_decls())
+                    .collect::<Vec<_>>();
+
+                if fields.style.is_tuple() {
+                    quote! {
+                        #vis struct #checker_name #generics (
+                            #(#field_decls,)*
+                        ) #where_clause;
+                    }
+                } else {
+                    quote! {
+                        #vis struct #checker_name #generics #where_clause {
+                            #(#field_decls,)*
+                        }
+                    }
+                }
+            }
+            Data::Enum(variants) => {
+                let variants = variants.iter().map(|variant| {
+                    let Variant {
+                        ident,
+                        attrs,
+                        fields,
+                    } = variant;
+                    let field_decls = self
+                        .receiver_fields(fields)
+                        .iter()
+                        .filter_map(ReceiverField::field_decl)
+                        .collect::<Vec<_>>();
+                    let doc = (!attrs.iter().any(|attr| attr.path().is_ident("doc"))).then(|| {
+                        let doc = format!(" Staging form of the `{}` variant.", ident.unraw());
+                        quote!(#[doc = #doc])
+                    });
+
+                    match fields.style {
+                        Style::Struct => quote! {
+                            #doc
+                            #(#attrs)*
+                            #ident { #(#field_decls,)* }
+                        },
+                        Style::Tuple => quote! {
+                            #doc
+                            #(#attrs)*
+                            #ident( #(#field_decls,)* )
+                        },
+                        Style::Unit => quote! {
+                            #doc
+                            #(#attrs)*
+                            #ident
+                        },
+                    }
+                });
+
+                quote! {
+                    #vis enum #checker_name #generics #where_clause {
+                        #(#variants,)*
+                    }
+                }
+            }
+        }
+    }
+
+    /// `From<Target>` impl that stores every field of the target as a successful value.
+    fn target_conversion_impl(&self) -> Option<TokenStream> {
+        if !self.from_target.is_present() {
+            return None;
+        }
+
+        let root = self.crate_root();
+        let ident = self.target();
+        let checker_name = self.checker_name();
+        let checker_generics = self.checker_generics();
+        let (impl_generics, checker_ty_generics, where_clause) = checker_generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        let body = match &self.data {
+            Data::Struct(fields) => {
+                let fields = self.receiver_fields(fields);
+                let pattern = destructure_with(&parse_quote!(#ident), &fields, None, |field| {
+                    Some(field.target_pat())
+                });
+                let values = fields.iter().filter_map(ReceiverField::stored_target_value);
+                let lists = self
+                    .additional_errors_member()
+                    .into_iter()
+                    .chain(self.warnings_member())
+                    .chain(self.extra_members())
+                    .map(|member| quote!(#member: #root::export::Default::default()));
+
+                quote! {
+                    let #pattern = value;
+                    Self {
+                        #(#values,)*
+                        #(#lists,)*
+                    }
+                }
+            }
+            Data::Enum(variants) => {
+                let arms = variants.iter().map(|variant| {
+                    let variant_ident = &variant.ident;
+                    let fields = self.receiver_fields(&variant.fields);
+                    let pattern = destructure_with(
+                        &parse_quote!(#ident::#variant_ident),
+                        &fields,
+                        None,
+                        |field| Some(field.target_pat()),
+                    );
+                    let values = fields.iter().filter_map(ReceiverField::stored_target_value);
+                    quote! {
+                        #pattern => #checker_name::#variant_ident {
+                            #(#values,)*
+                        }
+                    }
+                });
+
+                quote! {
+                    match value {
+                        #(#arms,)*
+                    }
+                }
+            }
+        };
+        let automatically_derived = self.automatically_derived();
+
+        Some(quote! {
+            #automatically_derived
+            impl #impl_generics #root::export::From<#ident #ty_generics> for #checker_name #checker_ty_generics #where_clause {
+                fn from(value: #ident #ty_generics) -> Self {
+                    #body
+                }
+            }
+        })
+    }
+
+    /// `FIELDS` constant listing the name of each field of the struct that the checker
+    /// stores.
+    fn fields_const(&self) -> Option<syn::ImplItemConst> {
+        let Data::Struct(fields) = &self.data else {
+            return None;
+        };
+
+        let vis = self.checker_vis();
+        let fields = self.receiver_fields(fields);
+        let names = fields
+            .iter()
+            .filter(|field| !field.is_skipped())
+            .map(|field| {
+                let cfg = field.cfg_attrs();
+                let name = field.name();
+                quote!(#(#cfg)* #name)
+            });
+
+        Some(parse_quote! {
+            /// Name of each field that the checker stores, in the order they are declared.
+            #vis const FIELDS: &'static [&'static str] = &[#(#names),*];
+        })
+    }
+
+    /// Name of the struct emitted by `as_ref`.
+    fn ref_name(&self) -> Ident {
+        format_ident!("{}Ref", self.checker_name())
+    }
+
+    /// Name of the struct emitted by `patch`, if set.
+    fn patch_name(&self) -> Option<Ident> {
+        Some(match self.patch.as_ref()? {
+            Override::Explicit(name) => name.clone(),
+            Override::Inherit => format_ident!("{}Patch", self.ident),
+        })
+    }
+
+    /// Name of the enum emitted by `field_enum`, if set.
+    fn field_enum_name(&self) -> Option<Ident> {
+        Some(match self.field_enum.as_ref()? {
+            Override::Explicit(name) => name.clone(),
+            Override::Inherit => format_ident!("{}Field", self.ident),
+        })
+    }
+
+    /// Move the impls among `items` into an anonymous `const` that brings the traits whose
+    /// methods they call into scope from `export`, so they compile without the prelude.
+    fn scope_impls(&self, items: TokenStream) -> TokenStream {
+        let Ok(file) = syn::parse2::<syn::File>(items.clone()) else {
+            return items;
+        };
+
+        let root = self.crate_root();
+        let (impls, decls): (Vec<_>, Vec<_>) = file
+            .items
+            .into_iter()
+            .partition(|item| matches!(item, syn::Item::Impl(_)));
+        quote! {
+            #(#decls)*
+
+            const _: () = {
+                #[allow(unused_imports)]
+                use #root::export::{
+                    Clone as _, DoubleEndedIterator as _, Extend as _, From as _, Into as _,
+                    IntoIterator as _, Iterator as _,
+                };
+
+                #(#impls)*
+            };
+        }
+    }
+
+    /// Name of every type emitted for the deriving type, starting with the checker.
+    fn generated_types(&self) -> Vec<Ident> {
+        let is_struct = !self.data.is_enum();
+        let mut types = vec![self.checker_name()];
+        types.extend(is_struct.then(|| self.field_enum_name()).flatten());
+        types.extend(is_struct.then(|| self.patch_name()).flatten());
+        if is_struct && self.as_ref.is_present() {
+            types.push(self.ref_name());
+        }
+        types
+    }
+
+    /// Rewrite every visibility the checker is declared with so that it reaches just as
+    /// far from inside the `module` it is placed in.
+    fn nest_visibility(&mut self) {
+        self.vis = nested_vis(&self.vis);
+        if let Some(vis) = &mut self.checker_vis {
+            *vis = nested_vis(vis);
+        }
+        if let Some(vis) = &mut self.field_vis {
+            *vis = nested_vis(vis);
+        }
+        if let Some(additional_errors) = &mut self.additional_errors
+            && let Some(vis) = &mut additional_errors.vis
+        {
+            *vis = nested_vis(vis);
+        }
+        for field in &mut self.extra.0 {
+            if !matches!(field.vis, syn::Visibility::Inherited) {
+                field.vis = nested_vis(&field.vis);
+            }
+        }
+        let fields: Box<dyn Iterator<Item = &mut InputField>> = match &mut self.data {
+            Data::Struct(fields) => Box::new(fields.fields.iter_mut()),
+            Data::Enum(variants) => Box::new(
+                variants
+                    .iter_mut()
+                    .flat_map(|variant| variant.fields.fields.iter_mut()),
+            ),
+        };
+        for field in fields {
+            if let Some(vis) = &mut field.checker_vis {
+                *vis = nested_vis(vis);
+            }
+        }
+    }
+
+    /// Generics of the checker with the lifetime of the borrow made by `as_ref` added.
+    fn ref_generics(&self) -> syn::Generics {
+        let mut generics = self.checker_generics().into_owned();
+        generics.params.insert(0, parse_quote!('__staging));
+        generics
+    }
+
+    /// Struct that borrows each field of the checker, emitted by `as_ref`.
+    fn ref_decl(&self) -> Option<TokenStream> {
+        if !self.as_ref.is_present() {
+            return None;
+        }
+        let Data::Struct(fields) = &self.data else {
+            return None;
+        };
+
+        let root = self.crate_root();
+        let vis = self.checker_vis();
+        let name = self.ref_name();
+        let generics = self.ref_generics();
+        let where_clause = &generics.where_clause;
+        let lifetime: syn::Lifetime = parse_quote!('__staging);
+        let fields = self.receiver_fields(fields);
+        let decls = fields.iter().filter_map(|field| {
+            if field.is_skipped() {
+                return None;
+            }
+
+            let docs = field.doc_attrs(format!(" Borrow of `{}` in the checker.", field.name()));
+            let cfg = field.cfg_attrs();
+            let vis = field.vis();
+            let (ty, _) = field.borrowed(Some(&lifetime), false);
+            Some(match field.checker_member() {
+                Member::Named(ident) => quote!(#(#docs)* #(#cfg)* #vis #ident: #ty),
+                Member::Unnamed(_) => quote!(#(#docs)* #(#cfg)* #vis #ty),
+            })
+        });
+        let body = match self.style() {
+            Style::Tuple => quote!((#(#decls),*) #where_clause;),
+            _ => quote!(#where_clause { #(#decls),* }),
+        };
+        let doc = format!(
+            " Borrowed view of each field of [`{}`].",
+            self.checker_name()
+        );
+        let doc_hidden = self.doc_hidden();
+
+        Some(quote! {
+            #[doc = #doc]
+            #doc_hidden
+            #[derive(#root::export::Clone, #root::export::Copy)]
+            #vis struct #name #generics #body
+        })
+    }
+
+    /// Patch struct with an `Option` of each field that the checker stores, and the
+    /// target's `apply` method that writes it.
+    fn patch_decl(&self) -> Option<TokenStream> {
+        let name = self.patch_name()?;
+        let Data::Struct(fields) = &self.data else {
+            return None;
+        };
+
+        let root = self.crate_root();
+        let ident = &self.ident;
+        let vis = &self.vis;
+        let generics = &self.generics;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let fields = self.receiver_fields(fields);
+        let fields = fields
+            .iter()
+            .filter(|field| !field.is_skipped())
+            .collect::<Vec<_>>();
+        let decls = fields.iter().map(|field| field.patch_field_decl());
+        let updates = fields.iter().map(|field| {
+            let cfg = field.cfg_attrs();
+            let member = field.member();
+            let patch_member = field.checker_member();
+            quote! {
+                #(#cfg)*
+                if let #root::export::Some(value) = patch.#patch_member {
+                    self.#member = value;
+                }
+            }
+        });
+        let body = match fields.first().map(|field| field.checker_member()) {
+            Some(Member::Unnamed(_)) => quote!((#(#decls),*) #where_clause;),
+            _ => quote!(#where_clause { #(#decls),* }),
+        };
+        let doc = format!(" Changes to some of the fields of [`{}`].", ident);
+        let doc_hidden = self.doc_hidden();
+
+        Some(quote! {
+            #[doc = #doc]
+            #doc_hidden
+            #[derive(#root::export::Default)]
+            #vis struct #name #generics #body
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Overwrite each field that is set in `patch`.
+                #vis fn apply(&mut self, patch: #name #ty_generics) {
+                    #(#updates)*
+                }
+            }
+        })
+    }
+
+    /// Enum naming each field of the struct that the checker stores.
+    fn field_enum_decl(&self) -> Option<TokenStream> {
+        let name = self.field_enum_name()?;
+        let Data::Struct(fields) = &self.data else {
+            return None;
+        };
+
+        let root = self.crate_root();
+        let vis = &self.vis;
+        let fields = self.receiver_fields(fields);
+        let fields = fields
+            .iter()
+            .filter(|field| !field.is_skipped())
+            .collect::<Vec<_>>();
+        let variants = fields.iter().map(|field| {
+            let docs = field.doc_attrs(format!(" The `{}` field.", field.name()));
+            let cfg = field.cfg_attrs();
+            let variant = field.variant_ident();
+            quote!(#(#docs)* #(#cfg)* #variant)
+        });
+        let arms = fields.iter().map(|field| {
+            let cfg = field.cfg_attrs();
+            let variant = field.variant_ident();
+            let field_name = field.name();
+            quote!(#(#cfg)* #name::#variant => #field_name)
+        });
+        let doc = format!(" Names of the fields of [`{}`].", self.ident);
+        let automatically_derived = self.automatically_derived();
+        let doc_hidden = self.doc_hidden();
+
+        Some(quote! {
+            #[doc = #doc]
+            #doc_hidden
+            #[derive(
+                #root::export::fmt::Debug,
+                #root::export::Clone,
+                #root::export::Copy,
+                #root::export::PartialEq,
+                #root::export::Eq,
+                #root::export::PartialOrd,
+                #root::export::Ord,
+                #root::export::Hash,
+            )]
+            #vis enum #name {
+                #(#variants,)*
+            }
+
+            impl #name {
+                /// Name of the field, as written in the struct.
+                #vis fn as_str(self) -> &'static str {
+                    match self {
+                        #(#arms,)*
+                    }
+                }
+            }
+
+            #automatically_derived
+            impl #root::export::fmt::Display for #name {
+                fn fmt(&self, f: &mut #root::export::fmt::Formatter<'_>) -> #root::export::fmt::Result {
+                    f.write_str(self.as_str())
+                }
+            }
+        })
+    }
+
+    /// The `serde` options to put on the checker as attributes. With `deserialize`, they
+    /// are read by the generated `Deserialize` impl, and are only forwarded for a derived
+    /// `Serialize`, since the `serde` attribute does not exist without a serde derive.
+    fn forwarded_serde<'a>(&self, serde: &'a [AttrArgs]) -> &'a [AttrArgs] {
+        let derives_serialize = self
+            .derive
+            .as_ref()
+            .is_some_and(|derive| derive.iter().any(is_serialize));
+        if self.deserialize.is_present() && !derives_serialize {
+            &[]
+        } else {
+            serde
+        }
+    }
+
+    /// With `deserialize`, the checker's `Deserialize` impl, which starts from `new` so
+    /// fields the document leaves out hold their missing error.
+    fn deserialize_impl(&self) -> Option<TokenStream> {
+        if !self.deserialize.is_present() {
+            return None;
+        }
+        let Data::Struct(fields) = &self.data else {
+            return None;
+        };
+
+        let root = self.crate_root();
+        let checker_name = self.checker_name();
+        let name = checker_name.to_string();
+        let generics = self.checker_generics();
+        let (_, ty_generics, _) = generics.split_for_impl();
+        let mut de_generics = generics.clone().into_owned();
+        de_generics.params.insert(0, parse_quote!('de));
+        let fields = self.receiver_fields(fields);
+        if self.generics.type_params().next().is_some() {
+            de_generics
+                .make_where_clause()
+                .predicates
+                .extend(fields.iter().filter_map(ReceiverField::deserialize_bound));
+        }
+        let (impl_generics, _, where_clause) = de_generics.split_for_impl();
+
+        let options = SerdeOptions::parse(&self.serde, &["rename_all", "deny_unknown_fields"])
+            .unwrap_or_default();
+        let rename_all = options.rename_all.as_deref();
+        let keys = fields
+            .iter()
+            .filter_map(|field| field.deserialize_key(rename_all));
+        let arms = fields
+            .iter()
+            .filter_map(|field| field.deserialize_arm(rename_all));
+        let deny_unknown_fields = options.deny_unknown_fields;
+        let automatically_derived = self.automatically_derived();
+
+        Some(quote! {
+            #automatically_derived
+            impl #impl_generics #root::export::DeserializeFields<'de> for #checker_name #ty_generics #where_clause {
+                const NAME: &'static str = #name;
+                const FIELDS: &'static [&'static str] = &[#(#keys),*];
+                const DENY_UNKNOWN_FIELDS: bool = #deny_unknown_fields;
+
+                fn deserialize_field<__A: #root::export::serde::de::MapAccess<'de>>(
+                    &mut self,
+                    key: &str,
+                    map: &mut __A,
+                ) -> #root::export::Result<#root::export::Option<&'static str>, __A::Error> {
+                    match key {
+                        #(#arms)*
+                        _ => #root::export::Ok(#root::export::None),
+                    }
+                }
+            }
+
+            #automatically_derived
+            impl #impl_generics #root::export::serde::Deserialize<'de> for #checker_name #ty_generics #where_clause {
+                fn deserialize<__D: #root::export::serde::Deserializer<'de>>(
+                    deserializer: __D,
+                ) -> #root::export::Result<Self, __D::Error> {
+                    #root::export::deserialize_checker(deserializer, Self::new())
+                }
+            }
+        })
+    }
+
+    /// `Default` impl that delegates to the generated `new` function.
+    fn default_impl(&self) -> Option<TokenStream> {
+        if self.builder.is_none() && self.missing.is_none() {
+            return None;
+        }
+
+        let root = self.crate_root();
+        let checker_name = self.checker_name();
+        let generics = self.checker_generics();
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let automatically_derived = self.automatically_derived();
+        Some(quote! {
+            #automatically_derived
+            impl #impl_generics #root::export::Default for #checker_name #ty_generics #where_clause {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        })
+    }
+
+    /// A `Debug` impl for the checker that redacts secret fields, if the checker derives
+    /// `Debug` and has any.
+    fn debug_impl(&self) -> Option<TokenStream> {
+        let derives_debug = self
+            .derive
+            .as_ref()
+            .is_some_and(|derive| derive.iter().any(is_debug));
+        if !derives_debug || !self.fields().any(|field| field.secret.is_present()) {
+            return None;
+        }
+
+        let root = self.crate_root();
+        let checker_name = self.checker_name();
+        let mut generics = self.checker_generics().into_owned();
+        let predicates = &mut generics.make_where_clause().predicates;
+
+        let body = match &self.data {
+            Data::Struct(fields) => {
+                let fields = self.receiver_fields(fields);
+                let additional_errors = self
+                    .additional_errors_member()
+                    .map(|member| (member, format_ident!("__additional_errors")));
+                let pattern = destructure_with(
+                    &parse_quote!(#checker_name),
+                    &fields,
+                    additional_errors
+                        .as_ref()
+                        .map(|(member, binding)| (member, binding)),
+                    ReceiverField::debug_pat,
+                );
+                predicates.extend(fields.iter().filter_map(ReceiverField::debug_bound));
+                if additional_errors.is_some() {
+                    let errors_ty = self.additional_errors_type();
+                    predicates.push(parse_quote!(#errors_ty: #root::export::fmt::Debug));
+                }
+                let warnings = self
+                    .warnings_member()
+                    .map(|member| (member, format_ident!("__warnings")));
+                let warnings_binding = self.warnings.as_ref().zip(warnings.as_ref()).map(
+                    |(warning, (member, binding))| {
+                        predicates.push(parse_quote!(#warning: #root::export::fmt::Debug));
+                        quote!(let #binding = &self.#member;)
+                    },
+                );
+                let extras: Vec<_> = self
+                    .extra
+                    .0
+                    .iter()
+                    .filter_map(|field| {
+                        let ident = field.ident.as_ref()?;
+                        let ty = &field.ty;
+                        predicates.push(parse_quote!(#ty: #root::export::fmt::Debug));
+                        Some((
+                            Member::Named(ident.clone()),
+                            format_ident!("__extra_{}", ident.unraw()),
+                        ))
+                    })
+                    .collect();
+                let extra_bindings = extras
+                    .iter()
+                    .map(|(member, binding)| quote!(let #binding = &self.#member;));
+                let debug = debug_fields(
+                    &checker_name,
+                    self.style(),
+                    &fields,
+                    additional_errors
+                        .iter()
+                        .chain(&warnings)
+                        .chain(&extras)
+                        .map(|(member, binding)| (member, binding)),
+                );
+
+                quote! {
+                    let #pattern = self;
+                    #warnings_binding
+                    #(#extra_bindings)*
+                    #debug
+                }
+            }
+            Data::Enum(variants) => {
+                let arms = variants
+                    .iter()
+                    .map(|variant| {
+                        let variant_ident = &variant.ident;
+                        let fields = self.receiver_fields(&variant.fields);
+                        let pattern = destructure_with(
+                            &parse_quote!(#checker_name::#variant_ident),
+                            &fields,
+                            None,
+                            ReceiverField::debug_pat,
+                        );
+                        predicates.extend(fields.iter().filter_map(ReceiverField::debug_bound));
+                        let debug =
+                            debug_fields(variant_ident, variant.fields.style, &fields, None);
+
+                        quote! {
+                            #pattern => {
+                                #debug
+                            }
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        };
+
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let automatically_derived = self.automatically_derived();
+        Some(quote! {
+            #automatically_derived
+            impl #impl_generics #root::export::fmt::Debug for #checker_name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut #root::export::fmt::Formatter<'_>) -> #root::export::fmt::Result {
+                    #body
+                }
+            }
+        })
+    }
+
+    /// `TryFrom<&Checker>` impl for the target that finalizes a field-by-field clone of
+    /// the checker, so the checker does not itself need to implement `Clone`.
+    fn try_from_ref_impl(&self) -> Option<TokenStream> {
+        if !self.try_from_ref.is_present() {
+            return None;
+        }
+
+        let root = self.crate_root();
+        let ident = self.target();
+        let checker_name = self.checker_name();
+        let final_error = self.final_error();
+        let mut generics = self.checker_generics().into_owned();
+        let predicates = &mut generics.make_where_clause().predicates;
+        predicates.extend(self.final_error_bound());
+
+        let body =
+            match &self.data {
+                Data::Struct(fields) => {
+                    let fields = self.receiver_fields(fields);
+                    let additional_errors = self
+                        .additional_errors_member()
+                        .map(|member| (member, format_ident!("__additional_errors")));
+                    let pattern = destructure(
+                        &parse_quote!(#checker_name),
+                        &fields,
+                        additional_errors
+                            .as_ref()
+                            .map(|(member, binding)| (member, binding)),
+                    );
+                    predicates.extend(fields.iter().filter_map(ReceiverField::clone_bound));
+                    let clones = fields.iter().filter_map(ReceiverField::clone_value);
+                    let additional_errors = additional_errors.map(|(member, binding)| {
+                        let errors_ty = self.additional_errors_type();
+                        predicates.push(parse_quote!(#errors_ty: #root::export::Clone));
+                        quote!(#member: #root::export::Clone::clone(#binding))
+                    });
+                    let warnings = self.warnings.as_ref().zip(self.warnings_member()).map(
+                        |(warning, member)| {
+                            predicates.push(parse_quote!(#warning: #root::export::Clone));
+                            quote!(#member: #root::export::Clone::clone(&checker.#member))
+                        },
+                    );
+                    let extras = self.extra.0.iter().filter_map(|field| {
+                        let ident = field.ident.as_ref()?;
+                        let ty = &field.ty;
+                        predicates.push(parse_quote!(#ty: #root::export::Clone));
+                        Some(quote!(#ident: #root::export::Clone::clone(&checker.#ident)))
+                    });
+                    let lists = additional_errors.into_iter().chain(warnings).chain(extras);
+
+                    quote! {
+                        let #pattern = checker;
+                        #checker_name {
+                            #(#clones,)*
+                            #(#lists,)*
+                        }
+                    }
+                }
+                Data::Enum(variants) => {
+                    let arms = variants
+                        .iter()
+                        .map(|variant| {
+                            let variant_ident = &variant.ident;
+                            let fields = self.receiver_fields(&variant.fields);
+                            let pattern = destructure(
+                                &parse_quote!(#checker_name::#variant_ident),
+                                &fields,
+                                None,
+                            );
+                            predicates.extend(fields.iter().filter_map(ReceiverField::clone_bound));
+                            let clones = fields.iter().filter_map(ReceiverField::clone_value);
+
+                            quote! {
+                                #pattern => #checker_name::#variant_ident {
+                                    #(#clones,)*
+                                }
+                            }
+                        })
+                        .collect::<Vec<_>>();
+
+                    quote! {
+                        match checker {
+                            #(#arms,)*
+                        }
+                    }
+                }
+            };
+
+        let (impl_generics, checker_ty_generics, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let automatically_derived = self.automatically_derived();
+        Some(quote! {
+            #automatically_derived
+            impl #impl_generics #root::export::TryFrom<&#checker_name #checker_ty_generics> for #ident #ty_generics #where_clause {
+                type Error = #final_error;
+
+                fn try_from(checker: &#checker_name #checker_ty_generics) -> #root::export::Result<Self, Self::Error> {
+                    let checker = { #body };
+                    #root::export::TryFrom::try_from(checker)
+                }
+            }
+        })
+    }
+
+    /// Name of the hidden type that holds the values taken out of the checker by
+    /// `TakeValues`.
+    fn values_name(&self) -> Ident {
+        format_ident!("__{}Values", self.checker_name())
+    }
+
+    /// The lists and `extra` fields the checker keeps besides its fields, each with its
+    /// member, the binding it is moved into and its type.
+    fn checker_lists(&self) -> Vec<(Member, Ident, syn::Type)> {
+        let root = self.crate_root();
+        let additional_errors = self.additional_errors_member().map(|member| {
+            (
+                member,
+                format_ident!("__additional_errors"),
+                self.additional_errors_type(),
+            )
+        });
+        let warnings =
+            self.warnings
+                .as_ref()
+                .zip(self.warnings_member())
+                .map(|(warning, member)| {
+                    (
+                        member,
+                        format_ident!("__warnings"),
+                        parse_quote!(#root::export::Vec<#warning>),
+                    )
+                });
+        let extras = self.extra.0.iter().filter_map(|field| {
+            let ident = field.ident.clone()?;
+            let binding = format_ident!("__extra_{}", ident.unraw());
+            Some((Member::Named(ident), binding, field.ty.clone()))
+        });
+        additional_errors
+            .into_iter()
+            .chain(warnings)
+            .chain(extras)
+            .collect()
+    }
+
+    /// With `try_finalize`, the hidden type that holds the values of a checker without
+    /// errors, and the `TakeValues` impl that moves them out of the checker and back.
+    fn take_values_impl(&self) -> Option<TokenStream> {
+        if !self.try_finalize.is_present() {
+            return None;
+        }
+
+        let root = self.crate_root();
+        let vis = self.checker_vis();
+        let ident = self.target();
+        let checker_name = self.checker_name();
+        let values_name = self.values_name();
+        let generics = self.checker_generics();
+        let (impl_generics, checker_ty_generics, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let marker = quote! {
+            __marker: #root::export::PhantomData<fn() -> #checker_name #checker_ty_generics>
+        };
+
+        let (decl, take, restore, build) = match &self.data {
+            Data::Struct(fields) => {
+                let fields = self.receiver_fields(fields);
+                let shape = ValuesShape::new(
+                    self,
+                    parse_quote!(#checker_name),
+                    parse_quote!(#values_name),
+                    parse_quote!(#ident),
+                    &fields,
+                    self.checker_lists(),
+                );
+                let ValuesShape {
+                    decls,
+                    checker_pat,
+                    values_pat,
+                    take,
+                    restore,
+                    build,
+                    ..
+                } = shape;
+
+                (
+                    quote! {
+                        #vis struct #values_name #generics #where_clause {
+                            #(#decls,)*
+                            #marker,
+                        }
+                    },
+                    quote! {
+                        let #checker_pat = self;
+                        #take
+                    },
+                    quote! {
+                        let #values_pat = values;
+                        #restore
+                    },
+                    quote! {
+                        let #values_pat = values;
+                        #build
+                    },
+                )
+            }
+            Data::Enum(variants) => {
+                let shapes = variants
+                    .iter()
+                    .map(|variant| {
+                        let variant_ident = &variant.ident;
+                        let fields = self.receiver_fields(&variant.fields);
+                        (
+                            variant_ident,
+                            ValuesShape::new(
+                                self,
+                                parse_quote!(#checker_name::#variant_ident),
+                                parse_quote!(#values_name::#variant_ident),
+                                parse_quote!(#ident::#variant_ident),
+                                &fields,
+                                vec![],
+                            ),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let decls = shapes.iter().map(|(variant_ident, shape)| {
+                    let decls = &shape.decls;
+                    quote!(#variant_ident { #(#decls,)* #marker })
+                });
+                let take = shapes.iter().map(|(_, shape)| {
+                    let ValuesShape {
+                        checker_pat, take, ..
+                    } = shape;
+                    quote!(#checker_pat => { #take })
+                });
+                let restore = shapes.iter().map(|(_, shape)| {
+                    let ValuesShape {
+                        values_pat,
+                        restore,
+                        ..
+                    } = shape;
+                    quote!(#values_pat => #restore)
+                });
+                let build = shapes.iter().map(|(_, shape)| {
+                    let ValuesShape {
+                        values_pat, build, ..
+                    } = shape;
+                    quote!(#values_pat => { #build })
+                });
+
+                (
+                    quote! {
+                        #vis enum #values_name #generics #where_clause {
+                            #(#decls,)*
+                        }
+                    },
+                    quote!(match self { #(#take)* }),
+                    quote!(match values { #(#restore,)* }),
+                    quote!(match values { #(#build)* }),
+                )
+            }
+        };
+
+        let automatically_derived = self.automatically_derived();
+        Some(quote! {
+            /// The values of a checker that holds no errors, as taken by `TakeValues`.
+            #[doc(hidden)]
+            #decl
+
+            #automatically_derived
+            impl #impl_generics #root::export::TakeValues for #checker_name #checker_ty_generics #where_clause {
+                type Values = #values_name #checker_ty_generics;
+                type Target = #ident #ty_generics;
+
+                fn take_values(self) -> #root::export::Result<Self::Values, Self> {
+                    #take
+                }
+
+                fn restore(values: Self::Values) -> Self {
+                    #restore
+                }
+
+                fn build(values: Self::Values) -> Self::Target {
+                    #build
+                }
+            }
+        })
+    }
+
+    /// Expression that is true if `self` holds any error that finalizing would report, or
+    /// with `count_defaults`, also one in a field with a `default`.
+    fn has_errors_body(&self, count_defaults: bool) -> TokenStream {
+        let root = self.crate_root();
+        let checker_name = self.checker_name();
+
+        match &self.data {
+            Data::Struct(fields) => {
+                let fields = self.receiver_fields(fields);
+                let additional_errors = self
+                    .additional_errors_member()
+                    .map(|member| (member, format_ident!("__additional_errors")));
+                let pattern = destructure_with(
+                    &parse_quote!(#checker_name),
+                    &fields,
+                    additional_errors
+                        .as_ref()
+                        .map(|(member, binding)| (member, binding)),
+                    |field| field.has_errors_pat(count_defaults),
+                );
+                let checks = fields
+                    .iter()
+                    .filter_map(|field| field.has_errors(count_defaults))
+                    .chain(
+                        additional_errors.map(|(_, binding)| match self.is_severe() {
+                            Some(is_severe) => quote! {
+                                #root::export::Iterator::any(
+                                    &mut #root::export::IntoIterator::into_iter(#binding),
+                                    #is_severe,
+                                )
+                            },
+                            None => quote! {
+                                #root::export::Iterator::next(
+                                    &mut #root::export::IntoIterator::into_iter(#binding),
+                                )
+                                .is_some()
+                            },
+                        }),
+                    );
+
+                quote! {
+                    let #pattern = self;
+                    false #(|| #checks)*
+                }
+            }
+            Data::Enum(variants) => {
+                let arms = variants.iter().map(|variant| {
+                    let variant_ident = &variant.ident;
+                    let fields = self.receiver_fields(&variant.fields);
+                    let pattern = destructure_with(
+                        &parse_quote!(#checker_name::#variant_ident),
+                        &fields,
+                        None,
+                        |field| field.has_errors_pat(count_defaults),
+                    );
+                    let checks = fields
+                        .iter()
+                        .filter_map(|field| field.has_errors(count_defaults));
+
+                    quote! {
+                        #pattern => false #(|| #checks)*
+                    }
+                });
+
+                quote! {
+                    match self {
+                        #(#arms,)*
+                    }
+                }
+            }
+        }
+    }
+
+    /// Statements that borrow each field of `self` and run `field_stmt` for it, followed by
+    /// pushing the additional errors onto `__errors` if `include_additional_errors` is set.
+    fn borrow_body<'a>(
+        &'a self,
+        field_stmt: impl Fn(&ReceiverField<'a>) -> Option<syn::Stmt> + Copy,
+        include_additional_errors: bool,
+    ) -> TokenStream {
+        let checker_name = self.checker_name();
+
+        match &self.data {
+            Data::Struct(fields) => {
+                let fields = self.receiver_fields(fields);
+                let additional_errors = self
+                    .additional_errors_member()
+                    .map(|member| (member, format_ident!("__additional_errors")));
+                let pattern = destructure_with(
+                    &parse_quote!(#checker_name),
+                    &fields,
+                    additional_errors
+                        .as_ref()
+                        .map(|(member, binding)| (member, binding)),
+                    ReceiverField::borrow_pat,
+                );
+                let borrows = fields.iter().filter_map(field_stmt);
+                let additional_errors = additional_errors
+                    .filter(|_| include_additional_errors)
+                    .map(|(_, binding)| quote!(__errors.extend(#binding);));
+
+                quote! {
+                    let #pattern = self;
+                    #(#borrows)*
+                    #additional_errors
+                }
+            }
+            Data::Enum(variants) => {
+                let arms = variants.iter().map(|variant| {
+                    let variant_ident = &variant.ident;
+                    let fields = self.receiver_fields(&variant.fields);
+                    let pattern = destructure_with(
+                        &parse_quote!(#checker_name::#variant_ident),
+                        &fields,
+                        None,
+                        ReceiverField::borrow_pat,
+                    );
+                    let borrows = fields.iter().filter_map(field_stmt);
+
+                    quote! {
+                        #pattern => {
+                            #(#borrows)*
+                        }
+                    }
+                });
+
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        }
+    }
+
+    /// Body of the finalize method, which consumes `self` and produces the target or the
+    /// list of errors.
+    fn finalize_body(&self, mode: Finalize) -> TokenStream {
+        let ident = self.target();
+        let checker_name = self.checker_name();
+        let pre_finalize = self.pre_finalize.as_ref().map(|path| {
+            let root = self.crate_root();
+            let error = self.error();
+            let ctx = self.context.as_ref().map(|_| quote!(, __ctx));
+            quote! {
+                let __pre_finalize: #root::export::Vec<#error> =
+                    #root::export::IntoIterator::into_iter(#path(&self #ctx))
+                        .map(#root::export::Into::into)
+                        .collect();
+            }
+        });
+
+        let body = match &self.data {
+            Data::Struct(fields) => {
+                let fields = self.receiver_fields(fields);
+                let additional_errors = self
+                    .additional_errors_member()
+                    .map(|member| (member, format_ident!("__additional_errors")));
+                let pattern = destructure(
+                    &parse_quote!(#checker_name),
+                    &fields,
+                    additional_errors
+                        .as_ref()
+                        .map(|(member, binding)| (member, binding)),
+                );
+                let finalize = self.finalize_fields(
+                    &parse_quote!(#ident),
+                    &fields,
+                    additional_errors.as_ref().map(|(_, binding)| binding),
+                    mode,
+                );
+
+                quote! {
+                    let #pattern = self;
+                    #finalize
+                }
+            }
+            Data::Enum(variants) => {
+                let arms = variants.iter().map(|variant| {
+                    let variant_ident = &variant.ident;
+                    let fields = self.receiver_fields(&variant.fields);
+                    let pattern =
+                        destructure(&parse_quote!(#checker_name::#variant_ident), &fields, None);
+                    let finalize = self.finalize_fields(
+                        &parse_quote!(#ident::#variant_ident),
+                        &fields,
+                        None,
+                        mode,
+                    );
+
+                    quote! {
+                        #pattern => {
+                            #finalize
+                        }
+                    }
+                });
+
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        };
+
+        // Bind the context before the fields, which may shadow `ctx`.
+        let ctx = self.context.as_ref().map(|_| quote!(let __ctx = ctx;));
+
+        quote! {
+            #ctx
+            #pre_finalize
+            #body
+        }
+    }
+
+    /// Statements that record an error for each `at_least_one_of` group with no provided
+    /// fields, or return it with `Finalize::FirstError`.
+    fn at_least_one_of_checks(&self, fields: &[ReceiverField], mode: Finalize) -> Vec<syn::Stmt> {
+        let root = self.crate_root();
+        self.at_least_one_of
+            .iter()
+            .map(|group| {
+                let members = group
+                    .fields
+                    .iter()
+                    .filter_map(|name| {
+                        fields
+                            .iter()
+                            .find(|field| field.field.ident.as_ref() == Some(name))
+                    })
+                    .collect::<Vec<_>>();
+                let provided = members.iter().map(|field| field.provided(mode));
+                let cfg = members.iter().flat_map(|field| field.cfg_attrs());
+                let error = &group.error;
+                let push = match mode {
+                    Finalize::FirstError => {
+                        quote!(return #root::export::Err(#root::export::Into::into(#error));)
+                    }
+                    _ => self.push_error(quote!(#root::export::Into::into(#error))),
+                };
+                parse_quote! {
+                    #(#cfg)*
+                    if !(#(#provided)||*) {
+                        #push
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Expression that builds the target from `initializers` once every field has a
+    /// value, running `post_validate` on it if set.
+    fn finish_value(
+        &self,
+        target: &Path,
+        initializers: impl Iterator<Item = syn::FieldValue>,
+        mode: Finalize,
+    ) -> TokenStream {
+        let root = self.crate_root();
+        let value = quote! {
+            #target {
+                #(#initializers),*
+            }
+        };
+
+        let Some(PostValidate { path, multiple }) = &self.post_validate else {
+            return quote!(#root::export::Ok(#value));
+        };
+
+        let ctx = self.context.as_ref().map(|_| quote!(, __ctx));
+        let convert = quote!(#root::export::Into::into);
+        let collected = self.collected_errors();
+        // With `multiple`, the errors after the first are left in `__rest`.
+        let rest = multiple.is_present().then(|| quote!(__rest));
+        let fail = match mode {
+            Finalize::FirstError => quote!(return #root::export::Err(#convert(__first));),
+            Finalize::Grouped => {
+                let first =
+                    quote!(#root::export::IntoIterator::into_iter(#root::export::Some(__first)));
+                let errors = match &rest {
+                    Some(rest) => quote!(#root::export::Iterator::chain(#first, #rest)),
+                    None => first,
+                };
+                quote! {
+                    return #root::export::Err(#root::export::Iterator::collect(
+                        #root::export::Iterator::map(#errors, |err| (#root::export::None, #convert(err))),
+                    ));
+                }
+            }
+            Finalize::NonEmpty => {
+                let extend = rest.map(|rest| {
+                    quote! {
+                        #root::export::Extend::extend(
+                            &mut __errors,
+                            #root::export::Iterator::map(#rest, #convert),
+                        );
+                    }
+                });
+                quote! {
+                    let mut __errors = #root::export::AtLeastOne::new(#convert(__first));
+                    #extend
+                    return #root::export::Err(__errors);
+                }
+            }
+            Finalize::All => {
+                let push = self.push_error(quote!(#convert(__first)));
+                let extend = rest.map(|rest| {
+                    self.extend_errors(quote!(#root::export::Iterator::map(#rest, #convert)))
+                });
+                quote! {
+                    let mut __errors = <#collected as #root::export::Default>::default();
+                    #push
+                    #extend
+                    return #root::export::Err(__errors);
+                }
+            }
+        };
+        // With `multiple`, a function that returns `Err` with no errors in it passes, so
+        // a failed finalize always reports at least one error.
+        let check = if multiple.is_present() {
+            quote! {
+                if let #root::export::Err(errors) = #path(&__value #ctx) {
+                    let mut __rest = #root::export::IntoIterator::into_iter(errors);
+                    if let #root::export::Some(__first) = #root::export::Iterator::next(&mut __rest) {
+                        #fail
+                    }
+                }
+            }
+        } else {
+            quote! {
+                if let #root::export::Err(__first) = #path(&__value #ctx) {
+                    #fail
+                }
+            }
+        };
+        quote! {
+            let __value = #value;
+            #check
+            #root::export::Ok(__value)
+        }
+    }
+
+    fn finalize_fields(
+        &self,
+        target: &Path,
+        fields: &[ReceiverField],
+        additional_errors: Option<&Ident>,
+        mode: Finalize,
+    ) -> TokenStream {
+        let root = self.crate_root();
+        let error = self.error();
+        let initializers = fields.iter().map(ReceiverField::initializer);
+        let finish = self.finish_value(target, initializers, mode);
+
+        // Errors are collected field by field in ascending `order`, keeping declaration
+        // order for fields with the same `order`.
+        let mut ordered = fields.iter().collect::<Vec<_>>();
+        ordered.sort_by_key(|field| field.field.order.unwrap_or(0));
+
+        let mut constraints = ordered
+            .iter()
+            .flat_map(|field| {
+                let checks = field.constraint_checks(fields, mode);
+                let label = field.label_errors().filter(|_| {
+                    !checks.is_empty() && matches!(mode, Finalize::All | Finalize::Grouped)
+                });
+                checks.into_iter().map(|check| quote!(#check)).chain(label)
+            })
+            .collect::<Vec<_>>();
+        constraints.extend(
+            self.at_least_one_of_checks(fields, mode)
+                .into_iter()
+                .map(|check| quote!(#check)),
+        );
+
+        if !fields.iter().any(ReceiverField::is_fallible)
+            && additional_errors.is_none()
+            && self.pre_finalize.is_none()
+            && constraints.is_empty()
+        {
+            return quote! {
+                #finish
+            };
+        }
+
+        let pre_finalize = self
+            .pre_finalize
+            .as_ref()
+            .map(|_| self.record_errors(quote!(__pre_finalize), mode));
+        let position = self
+            .additional_errors
+            .as_ref()
+            .map(|additional_errors| additional_errors.position)
+            .unwrap_or_default();
+        let (prepend_errors, append_errors) = match additional_errors {
+            Some(errors) if position == Position::Last => {
+                (None, Some(self.record_errors(quote!(#errors), mode)))
+            }
+            Some(errors) => (Some(self.record_errors(quote!(#errors), mode)), None),
+            None => (None, None),
+        };
+
+        // With `field_errors`, record the name of the field each error came from in
+        // `__fields`, then pair the names with the errors once they are all collected.
+        let (fields_init, wrap_errors) = if self.field_errors.is_present() {
+            (
+                quote! {
+                    let mut __fields: #root::export::Vec<&'static str> = #root::export::Vec::new();
+                },
+                quote! {
+                    let __errors: #root::export::Vec<_> = __fields
+                        .into_iter()
+                        .zip(__errors)
+                        .map(|(field, source)| #root::export::FieldError::new(field, source))
+                        .collect();
+                },
+            )
+        } else {
+            (quote!(), quote!())
+        };
+
+        // With `dedup`, drop each error equal to an earlier one, along with its entries in
+        // the lists that run parallel to `__errors`.
+        let dedup = self.dedup.is_present().then(|| {
+            let parallel = self
+                .field_errors
+                .is_present()
+                .then(|| format_ident!("__fields"))
+                .into_iter()
+                .chain((mode == Finalize::Grouped).then(|| format_ident!("__groups")));
+            quote! {
+                let mut __index = 0;
+                while __index < __errors.len() {
+                    if __errors[..__index].contains(&__errors[__index]) {
+                        __errors.remove(__index);
+                        #(#parallel.remove(__index);)*
+                    } else {
+                        __index += 1;
+                    }
+                }
+            }
+        });
+
+        // Errors are only wrapped in a `FieldError` once they have all been found.
+        let errors: syn::Type = match &self.error_storage {
+            Some(ErrorStorage { container, .. }) => parse_quote!(#container),
+            None => parse_quote!(#root::export::Vec<#error>),
+        };
+
+        // Every check returns its error as soon as it is found, so no list is kept.
+        if mode == Finalize::FirstError {
+            let take_errors = ordered.iter().filter_map(|field| field.take_error(mode, 0));
+            return quote! {
+                #prepend_errors
+                #pre_finalize
+                #(#take_errors)*
+                #(#constraints)*
+                #append_errors
+
+                #finish
+            };
+        }
+
+        if mode == Finalize::NonEmpty {
+            return self.finalize_non_empty(
+                &ordered,
+                prepend_errors,
+                pre_finalize,
+                constraints,
+                append_errors,
+                finish,
+            );
+        }
+
+        // The number of checks that can still record an error when each field is taken,
+        // counting that field.
+        let mut remaining = ordered
+            .iter()
+            .rev()
+            .scan(constraints.len(), |remaining, field| {
+                *remaining += usize::from(field.is_fallible());
+                Some(*remaining)
+            })
+            .collect::<Vec<_>>();
+        remaining.reverse();
+
+        // Fields without a value recorded an error when they were taken, so the target is
+        // built only once every field holds a value and no other check recorded one.
+        let take_values = fields.iter().filter_map(ReceiverField::take_value);
+        let found_errors = if self.error_storage.is_some() {
+            quote!(#root::export::Iterator::next(&mut #root::export::IntoIterator::into_iter(&__errors)).is_some())
+        } else {
+            quote!(!__errors.is_empty())
+        };
+        let finish = quote! {
+            'finish: {
+                #(#take_values)*
+                if #found_errors {
+                    break 'finish;
+                }
+
+                return { #finish };
+            }
+        };
+
+        if mode == Finalize::All {
+            let take_errors = ordered
+                .iter()
+                .zip(&remaining)
+                .filter_map(|(field, remaining)| {
+                    let take_error = field.take_error(mode, *remaining)?;
+                    let label = field.label_errors();
+                    Some(quote! {
+                        #take_error
+                        #label
+                    })
+                });
+            return quote! {
+                let mut __errors: #errors = #root::export::Default::default();
+                #prepend_errors
+                #pre_finalize
+                #fields_init
+                #(#take_errors)*
+                #(#constraints)*
+                #append_errors
+
+                #finish
+
+                #dedup
+                #wrap_errors
+                #root::export::Err(__errors)
+            };
+        }
+
+        // Label each error with the group of the field it came from as soon as it has
+        // been recorded. Errors not tied to a field have no group.
+        let take_errors = ordered
+            .iter()
+            .zip(&remaining)
+            .filter_map(|(field, remaining)| {
+                let take_error = field.take_error(mode, *remaining)?;
+                let label = field.label_errors();
+                let group = match &field.field.group {
+                    Some(group) => quote!(#root::export::Some(#group)),
+                    None => quote!(#root::export::None),
+                };
+                Some(quote! {
+                    #take_error
+                    #label
+                    __groups.resize(__errors.len(), #group);
+                })
+            });
+
+        quote! {
+            let mut __errors: #root::export::Vec<#error> = #root::export::Vec::new();
+            #prepend_errors
+            #pre_finalize
+            #fields_init
+            let mut __groups: #root::export::Vec<#root::export::Option<&'static str>> =
+                #root::export::Vec::new();
+            __groups.resize(__errors.len(), #root::export::None);
+            #(#take_errors)*
+            #(#constraints)*
+            #append_errors
+            __groups.resize(__errors.len(), #root::export::None);
+
+            #finish
+
+            #dedup
+            #wrap_errors
+            #root::export::Err(__groups.into_iter().zip(__errors).collect())
+        }
+    }
+
+    /// Body of `finalize_fields` with `Finalize::NonEmpty`.
+    ///
+    /// Each field keeps its errors in its binding, and the bindings are folded into
+    /// `__state` in ascending `order`: it holds the values taken so far until a field
+    /// fails, then every error found, so a failed finalize always has one to report.
+    /// Errors recorded before the fields are taken start `__state` off, and those
+    /// recorded after are added once it has been folded.
+    fn finalize_non_empty(
+        &self,
+        ordered: &[&ReceiverField],
+        prepend_errors: Option<TokenStream>,
+        pre_finalize: Option<TokenStream>,
+        constraints: Vec<TokenStream>,
+        append_errors: Option<TokenStream>,
+        finish: TokenStream,
+    ) -> TokenStream {
+        let root = self.crate_root();
+        let collected = self.collected_error();
+        let take_errors = ordered
+            .iter()
+            .filter_map(|field| field.take_error(Finalize::NonEmpty, 0));
+
+        let start = if prepend_errors.is_some() || pre_finalize.is_some() {
+            quote! {
+                let mut __errors: #root::export::Vec<#collected> = #root::export::Vec::new();
+                #prepend_errors
+                #pre_finalize
+                let mut __early = #root::export::IntoIterator::into_iter(__errors);
+                let __state = match #root::export::Iterator::next(&mut __early) {
+                    #root::export::Some(__first) => {
+                        let mut __errors = #root::export::AtLeastOne::new(__first);
+                        #root::export::Extend::extend(&mut __errors, __early);
+                        #root::export::Err(__errors)
+                    }
+                    #root::export::None => #root::export::Ok(()),
+                };
+            }
+        } else {
+            quote! {
+                let __state: #root::export::Result<(), #root::export::AtLeastOne<#collected>> =
+                    #root::export::Ok(());
+            }
+        };
+
+        let has_late = !constraints.is_empty() || append_errors.is_some();
+        let late = has_late.then(|| {
+            quote! {
+                let mut __errors: #root::export::Vec<#collected> = #root::export::Vec::new();
+                #(#constraints)*
+                #append_errors
+            }
+        });
+
+        let fallible = ordered
+            .iter()
+            .filter(|field| field.is_fallible())
+            .collect::<Vec<_>>();
+        let folds = fallible.iter().map(|field| {
+            let binding = field.binding();
+            let cfg = field.cfg_attrs();
+            quote! {
+                #(#cfg)*
+                let __state = match (__state, #binding) {
+                    (#root::export::Ok(__values), #root::export::Ok(value)) => {
+                        #root::export::Ok((__values, value))
+                    }
+                    (#root::export::Ok(_), #root::export::Err(errors)) => #root::export::Err(errors),
+                    (#root::export::Err(__errors), #root::export::Ok(_)) => #root::export::Err(__errors),
+                    (#root::export::Err(mut __errors), #root::export::Err(errors)) => {
+                        #root::export::Extend::extend(&mut __errors, errors);
+                        #root::export::Err(__errors)
+                    }
+                };
+            }
+        });
+        let unfolds = fallible.iter().rev().map(|field| {
+            let binding = field.binding();
+            let cfg = field.cfg_attrs();
+            quote! {
+                #(#cfg)*
+                let (__values, #binding) = __values;
+            }
+        });
+
+        // With `dedup`, drop each error equal to an earlier one. With `field_errors`, the
+        // first field to report the error is kept.
+        let dedup = |errors: Ident| {
+            self.dedup.is_present().then(|| {
+                let same = if self.field_errors.is_present() {
+                    quote!(|a, b| a.source == b.source)
+                } else {
+                    quote!(|a, b| a == b)
+                };
+                quote!(#root::export::AtLeastOne::dedup_by(&mut #errors, #same);)
+            })
+        };
+
+        let dedup_late = dedup(format_ident!("__errors"));
+        let check_late = has_late.then(|| {
+            quote! {
+                let mut __late = #root::export::IntoIterator::into_iter(__errors);
+                if let #root::export::Some(__first) = #root::export::Iterator::next(&mut __late) {
+                    let mut __errors = #root::export::AtLeastOne::new(__first);
+                    #root::export::Extend::extend(&mut __errors, __late);
+                    #dedup_late
+                    return #root::export::Err(__errors);
+                }
+            }
+        });
+        let add_late = has_late.then(|| {
+            quote! {
+                #root::export::Extend::extend(&mut __failed, __errors);
+            }
+        });
+        let dedup_failed = dedup(format_ident!("__failed"));
+        let mutability = (has_late || dedup_failed.is_some()).then(|| quote!(mut));
+
+        quote! {
+            #start
+            #(#take_errors)*
+            #late
+            #(#folds)*
+
+            match __state {
+                #root::export::Ok(__values) => {
+                    #(#unfolds)*
+                    #check_late
+                    #finish
+                }
+                #root::export::Err(#mutability __failed) => {
+                    #add_late
+                    #dedup_failed
+                    #root::export::Err(__failed)
+                }
+            }
+        }
+    }
+}
+
+/// The parts of a `TakeValues` impl for one struct or enum variant.
+struct ValuesShape {
+    /// Declarations of the fields of the values type
+    decls: Vec<TokenStream>,
+    /// Pattern that moves every field and list out of the checker
+    checker_pat: syn::Pat,
+    /// Pattern that moves every field and list out of the values type
+    values_pat: syn::Pat,
+    /// Expression that takes the values out of the bindings of `checker_pat`, or puts
+    /// the checker back together if any field holds an error
+    take: TokenStream,
+    /// Expression that builds the checker from the bindings of `values_pat`
+    restore: TokenStream,
+    /// Statements and expression that build the target from the bindings of `values_pat`
+    build: TokenStream,
+}
+
+impl ValuesShape {
+    fn new(
+        receiver: &Receiver,
+        checker: Path,
+        values: Path,
+        target: Path,
+        fields: &[ReceiverField],
+        lists: Vec<(Member, Ident, syn::Type)>,
+    ) -> Self {
+        let root = receiver.crate_root();
+        let stored = fields
+            .iter()
+            .filter(|field| !field.is_skipped())
+            .collect::<Vec<_>>();
+        let bindings = stored
+            .iter()
+            .map(|field| {
+                let binding = field.binding();
+                let cfg = field.cfg_attrs();
+                quote!(#(#cfg)* #binding)
+            })
+            .collect::<Vec<_>>();
+        let list_members = lists
+            .iter()
+            .map(|(member, _, _)| member)
+            .collect::<Vec<_>>();
+        let list_bindings = lists
+            .iter()
+            .map(|(_, binding, _)| binding)
+            .collect::<Vec<_>>();
+
+        let decls = stored
+            .iter()
+            .map(|field| {
+                let binding = field.binding();
+                let cfg = field.cfg_attrs();
+                let ty = field.taken_type();
+                quote!(#(#cfg)* #binding: #ty)
+            })
+            .chain(lists.iter().map(|(_, binding, ty)| quote!(#binding: #ty)))
+            .collect();
+        let field_pats = stored.iter().filter_map(|field| field.field_pat());
+        let checker_pat = parse_quote! {
+            #checker { #(#field_pats,)* #(#list_members: #list_bindings,)* }
+        };
+        let values_pat = parse_quote! {
+            #values { #(#bindings,)* #(#list_bindings,)* __marker: _ }
+        };
+
+        let restore_fields = stored.iter().map(|field| {
+            let member = field.checker_member();
+            let cfg = field.cfg_attrs();
+            let binding = field.binding();
+            let value = field.restore_value(quote!(#binding));
+            quote!(#(#cfg)* #member: #value)
+        });
+        let restore = quote! {
+            #checker { #(#restore_fields,)* #(#list_members: #list_bindings,)* }
+        };
+
+        let built = stored.iter().map(|field| {
+            let binding = field.binding();
+            let cfg = field.cfg_attrs();
+            let value = field.build_value(quote!(#binding));
+            quote! {
+                #(#cfg)*
+                let #binding = #value;
+            }
+        });
+        let initializers = fields.iter().map(ReceiverField::initializer);
+        let build = quote! {
+            #(#built)*
+            #target { #(#initializers),* }
+        };
+
+        // The fields whose values are checked are gathered into a local struct, so
+        // they can be matched all at once even when some are removed by `cfg`.
+        let checked = stored
+            .iter()
+            .filter(|field| field.is_checked())
+            .collect::<Vec<_>>();
+        let guard = lists
+            .iter()
+            .find(|(member, _, _)| Some(member) == receiver.additional_errors_member().as_ref())
+            .map(|(_, binding, _)| {
+                quote! {
+                    if #root::export::Iterator::next(
+                        &mut #root::export::IntoIterator::into_iter(&#binding),
+                    )
+                    .is_none()
+                }
+            });
+        let taken_values = quote! {
+            #root::export::Ok(#values {
+                #(#bindings,)*
+                #(#list_bindings,)*
+                __marker: #root::export::PhantomData,
+            })
+        };
+        let take = if checked.is_empty() && guard.is_none() {
+            taken_values
+        } else {
+            let params = checked
+                .iter()
+                .map(|field| {
+                    let cfg = field.cfg_attrs();
+                    let param = format_ident!("__T{}", field.index);
+                    quote!(#(#cfg)* #param)
+                })
+                .collect::<Vec<_>>();
+            let taken_decls = checked.iter().map(|field| {
+                let binding = field.binding();
+                let cfg = field.cfg_attrs();
+                let param = format_ident!("__T{}", field.index);
+                quote!(#(#cfg)* #binding: #param)
+            });
+            let takes = checked.iter().map(|field| {
+                let binding = field.binding();
+                let cfg = field.cfg_attrs();
+                let take = field.take_checked(quote!(#binding));
+                quote!(#(#cfg)* #binding: #take)
+            });
+            let ok_pats = checked.iter().map(|field| {
+                let binding = field.binding();
+                let cfg = field.cfg_attrs();
+                quote!(#(#cfg)* #binding: #root::export::Ok(#binding))
+            });
+            let taken_pats = checked.iter().map(|field| {
+                let binding = field.binding();
+                let cfg = field.cfg_attrs();
+                quote!(#(#cfg)* #binding)
+            });
+            let put_back = stored.iter().map(|field| {
+                let binding = field.binding();
+                let member = field.checker_member();
+                let cfg = field.cfg_attrs();
+                let value = if field.is_checked() {
+                    let value = field.restore_value(quote!(value));
+                    quote! {
+                        match #binding {
+                            #root::export::Ok(value) => #value,
+                            #root::export::Err(stored) => stored,
+                        }
+                    }
+                } else {
+                    quote!(#binding)
+                };
+                quote!(#(#cfg)* #member: #value)
+            });
+
+            quote! {
+                struct __Taken<#(#params),*> {
+                    #(#taken_decls,)*
+                }
+
+                match (__Taken { #(#takes,)* }) {
+                    __Taken { #(#ok_pats,)* } #guard => #taken_values,
+                    __Taken { #(#taken_pats,)* } => #root::export::Err(#checker {
+                        #(#put_back,)*
+                        #(#list_members: #list_bindings,)*
+                    }),
+                }
+            }
+        };
+
+        Self {
+            decls,
+            checker_pat,
+            values_pat,
+            take,
+            restore,
+            build,
+        }
+    }
+}
+
+/// How finalization reports the errors it finds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Finalize {
+    /// Return every error in a `Vec`
+    All,
+    /// Return every error in a `Vec`, each with the group of the field it came from
+    Grouped,
+    /// Return the first error as soon as it is found
+    FirstError,
+    /// Return every error in an `AtLeastOne`, with each field's errors kept in its
+    /// binding until every field has been taken
+    NonEmpty,
+}
+
+/// Check that every field named by a constraint exists alongside the field that names it.
+fn check_constraints(fields: &Fields<InputField>) -> darling::Result<()> {
+    let mut errors = darling::Error::accumulator();
+
+    for field in fields.iter() {
+        for (name, constraint) in field.constraints() {
+            let target = fields
+                .iter()
+                .find(|other| other.ident.as_ref() == Some(&constraint.field));
+            let message = match target {
+                None => format!("Unknown field `{}`", constraint.field),
+                Some(target) if target.ident == field.ident => {
+                    format!("`{}` cannot name the field it is declared on", name)
+                }
+                Some(target) if target.skip.is_some() => {
+                    format!(
+                        "Field `{}` is skipped and cannot be used in `{}`",
+                        constraint.field, name
+                    )
+                }
+                Some(_) => continue,
+            };
+            errors.push(darling::Error::custom(message).with_span(&constraint.field));
+        }
+    }
+
+    errors.finish()
+}
+
+/// Whether an option that applies to `Result` fields can also be used with a storage mode.
+fn mode_allows(mode: &str, option: &str) -> bool {
+    matches!(
+        (mode, option),
+        (
+            "elements" | "entries" | "optional" | "spanned",
+            "error" | "map_err"
+        ) | ("multiple_errors", "default" | "error" | "map_err")
+    )
+}
+
+/// The first reference in `ty`, such as `&'a str` in `Option<&'a str>`.
+fn find_reference(ty: &syn::Type) -> Option<&syn::TypeReference> {
+    match ty {
+        syn::Type::Reference(reference) => Some(reference),
+        syn::Type::Array(array) => find_reference(&array.elem),
+        syn::Type::Slice(slice) => find_reference(&slice.elem),
+        syn::Type::Group(group) => find_reference(&group.elem),
+        syn::Type::Paren(paren) => find_reference(&paren.elem),
+        syn::Type::Tuple(tuple) => tuple.elems.iter().find_map(find_reference),
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .iter()
+            .filter_map(|segment| match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => Some(args),
+                _ => None,
+            })
+            .flat_map(|args| &args.args)
+            .find_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => find_reference(ty),
+                _ => None,
+            }),
+        _ => None,
+    }
+}
+
+/// The generic type arguments of the last segment of a type path, e.g. `T` in `Vec<T>`.
+fn type_arguments(ty: &syn::Type) -> Vec<&syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return vec![];
+    };
+
+    let Some(syn::PathArguments::AngleBracketed(args)) = type_path
+        .path
+        .segments
+        .last()
+        .map(|segment| &segment.arguments)
+    else {
+        return vec![];
+    };
+
+    args.args
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Replace the `n`th generic type argument of the last segment of a type path, e.g. to
+/// turn `HashMap<K, V>` into `HashMap<K, Result<V, E>>`.
+fn map_type_argument(
+    ty: &syn::Type,
+    n: usize,
+    f: impl FnOnce(&syn::Type) -> syn::Type,
+) -> syn::Type {
+    let mut ty = ty.clone();
+    if let syn::Type::Path(type_path) = &mut ty
+        && let Some(segment) = type_path.path.segments.last_mut()
+        && let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments
+        && let Some(syn::GenericArgument::Type(arg)) = args
+            .args
+            .iter_mut()
+            .filter(|arg| matches!(arg, syn::GenericArgument::Type(_)))
+            .nth(n)
+    {
+        *arg = f(arg);
+    }
+    ty
+}
+
+/// Infer the checker type for a field whose type derives `Staging` with the default name,
+/// e.g. `Address` becomes `AddressStaging` and `a::Pair<T>` becomes `a::PairStaging<T>`.
+fn inferred_checker(ty: &syn::Type) -> Option<syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+
+    if type_path.qself.is_some() {
+        return None;
+    }
+
+    let mut type_path = type_path.clone();
+    let last = type_path.path.segments.last_mut()?;
+    last.ident = format_ident!("{}Staging", last.ident);
+    Some(syn::Type::Path(type_path))
+}
+
+/// Visibility that reaches as far from inside a child module as `vis` does from its
+/// parent.
+fn nested_vis(vis: &syn::Visibility) -> syn::Visibility {
+    let syn::Visibility::Restricted(restricted) = vis else {
+        return match vis {
+            syn::Visibility::Inherited => parse_quote!(pub(super)),
+            _ => vis.clone(),
+        };
+    };
+
+    let path = &restricted.path;
+    match path.segments.first() {
+        Some(first) if path.leading_colon.is_none() && first.ident == "self" => {
+            let rest = path.segments.iter().skip(1);
+            parse_quote!(pub(in super #(::#rest)*))
+        }
+        Some(first) if path.leading_colon.is_none() && first.ident == "super" => {
+            parse_quote!(pub(in super::#path))
+        }
+        _ => vis.clone(),
+    }
+}
+
+/// Whether a path in the `derive` list refers to `Debug`.
+fn is_debug(path: &Path) -> bool {
+    path.segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Debug")
+}
+
+/// Whether a path in the `derive` list refers to `Default`.
+fn is_default(path: &Path) -> bool {
+    path.segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Default")
+}
+
+/// Whether a path in the `derive` list refers to `Deserialize`.
+fn is_deserialize(path: &Path) -> bool {
+    path.segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Deserialize")
+}
+
+/// Whether a path in the `derive` list refers to `Serialize`.
+fn is_serialize(path: &Path) -> bool {
+    path.segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Serialize")
+}
+
+/// Convert a `snake_case` field name to the case named by serde's `rename_all`, e.g.
+/// `camelCase`, or `None` if the case is not one serde knows.
+fn rename_case(name: &str, case: &str) -> Option<String> {
+    let capitalize = |word: &str| {
+        let mut chars = word.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+            .unwrap_or_default()
+    };
+    let words = name.split('_');
+    Some(match case {
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" => name.to_uppercase(),
+        "PascalCase" => words.map(capitalize).collect(),
+        "camelCase" => {
+            let pascal: String = words.map(capitalize).collect();
+            let mut chars = pascal.chars();
+            chars
+                .next()
+                .map(|first| first.to_lowercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+        "snake_case" => name.to_owned(),
+        "SCREAMING_SNAKE_CASE" => name.to_uppercase(),
+        "kebab-case" => name.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => name.replace('_', "-").to_uppercase(),
+        _ => return None,
+    })
+}
+
+/// Build a pattern that moves every field of `path` into its binding.
+fn destructure(
+    path: &Path,
+    fields: &[ReceiverField],
+    additional_errors: Option<(&Member, &Ident)>,
+) -> syn::Pat {
+    destructure_with(path, fields, additional_errors, ReceiverField::field_pat)
+}
+
+/// Build a pattern for `path`, using `field_pat` to produce the pattern for each field.
+///
+/// The pattern ignores any other field, such as the `warnings` list.
+fn destructure_with<'a>(
+    path: &Path,
+    fields: &[ReceiverField<'a>],
+    additional_errors: Option<(&Member, &Ident)>,
+    field_pat: impl Fn(&ReceiverField<'a>) -> Option<TokenStream>,
+) -> syn::Pat {
+    let field_pats = fields.iter().filter_map(field_pat);
+    let additional_errors = additional_errors
+        .into_iter()
+        .map(|(member, binding)| quote!(#member: #binding));
+
+    parse_quote! {
+        #path { #(#field_pats,)* #(#additional_errors,)* .. }
+    }
+}
+
+/// Format destructured field bindings, followed by the checker's lists, with the builder
+/// matching `style`.
+fn debug_fields<'a>(
+    name: &Ident,
+    style: Style,
+    fields: &[ReceiverField],
+    lists: impl IntoIterator<Item = (&'a Member, &'a Ident)>,
+) -> TokenStream {
+    let name = name.unraw().to_string();
+    let values = fields
+        .iter()
+        .filter(|field| field.field_pat().is_some())
+        .map(|field| {
+            let binding = field.binding();
+            let value = if field.field.secret.is_present() {
+                quote!(&format_args!("<redacted>"))
+            } else {
+                quote!(#binding)
+            };
+
+            match field.checker_member() {
+                Member::Named(ident) => {
+                    let ident = ident.unraw().to_string();
+                    quote!(.field(#ident, #value))
+                }
+                Member::Unnamed(_) => quote!(.field(#value)),
+            }
+        });
+    let lists = lists.into_iter().map(|(member, binding)| match member {
+        Member::Named(ident) => {
+            let ident = ident.unraw().to_string();
+            quote!(.field(#ident, #binding))
+        }
+        Member::Unnamed(_) => quote!(.field(#binding)),
+    });
+
+    match style {
+        Style::Struct => quote! {
+            f.debug_struct(#name) #(#values)* #(#lists)* .finish()
+        },
+        Style::Tuple => quote! {
+            f.debug_tuple(#name) #(#values)* #(#lists)* .finish()
+        },
+        Style::Unit => quote! {
+            f.write_str(#name)
+        },
+    }
+}
+
+impl ToTokens for Receiver {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let mut items = TokenStream::new();
+        let Self {
+            attrs,
+            derive,
+            serde,
+            checker_attrs,
+            ..
+        } = self;
+        let serde = self.forwarded_serde(serde);
+
+        let root = self.crate_root();
+        let ident = self.target();
+        let checker_name = self.checker_name();
+        let final_error = self.final_error();
+
+        // `Debug` is implemented by hand when it needs to redact secret fields, and
+        // `Default` when the checker has a `new` function.
+        let debug_impl = self.debug_impl();
+        let default_impl = self.default_impl();
+        let deserialize_impl = self.deserialize_impl();
+        let target_conversion_impl = self.target_conversion_impl();
+        let try_from_ref_impl = self.try_from_ref_impl();
+        let take_values_impl = self.take_values_impl();
+        let field_enum_decl = self.field_enum_decl();
+        let patch_decl = self.patch_decl();
+        let ref_decl = self.ref_decl();
+        let derive = derive.as_ref().map(|pl| {
+            let pl = pl
+                .iter()
+                .filter(|path| debug_impl.is_none() || !is_debug(path))
+                .filter(|path| default_impl.is_none() || !is_default(path));
+            quote! {
+                #[derive(#(#pl),*)]
+            }
+        });
+
+        let checker_generics = self.checker_generics();
+        let (impl_generics, checker_ty_generics, where_clause) = checker_generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        let mut try_from_generics = checker_generics.clone().into_owned();
+        if let Some(bound) = self.final_error_bound() {
+            try_from_generics.make_where_clause().predicates.push(bound);
+        }
+        let try_from_where_clause = &try_from_generics.where_clause;
+
+        let checker_decl = self.checker_decl();
+        let fields_const = self.fields_const();
+        let methods = self.inherent_methods();
+        let doc = self.checker_doc();
+        let separator = attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("doc"))
+            .then(|| quote!(#[doc = ""]));
+        let doc_hidden = self.doc_hidden();
+        let non_exhaustive = self
+            .non_exhaustive
+            .is_present()
+            .then(|| quote!(#[non_exhaustive]));
+
+        items.append_all(quote! {
+            #derive
+            #[doc = #doc]
+            #doc_hidden
+            #separator
+            #(#attrs)*
+            #(#[serde(#serde)])*
+            #(#checker_attrs)*
+            #non_exhaustive
+            #checker_decl
+
+            impl #impl_generics #checker_name #checker_ty_generics #where_clause {
+                #fields_const
+
+                #(#methods)*
+            }
+
+            #debug_impl
+
+            #default_impl
+
+            #deserialize_impl
+
+            #target_conversion_impl
+
+            #try_from_ref_impl
+
+            #take_values_impl
+
+            #field_enum_decl
+
+            #patch_decl
+
+            #ref_decl
+        });
+
+        // With `context`, finalizing needs an argument that `TryFrom` cannot take, so
+        // `try_into_with` is generated among the inherent methods instead. With
+        // `no_try_from`, `finalize` takes its place, and with `threshold`,
+        // `finalize_with_warnings` does.
+        let automatically_derived = self.automatically_derived();
+        let has_try_from =
+            self.context.is_none() && !self.no_try_from.is_present() && self.threshold.is_none();
+        if has_try_from {
+            let try_from_body = self.try_from_body(quote!(checker));
+            items.append_all(quote! {
+                #automatically_derived
+                impl #impl_generics #root::export::TryFrom<#checker_name #checker_ty_generics> for #ident #ty_generics #try_from_where_clause {
+                    type Error = #final_error;
+
+                    fn try_from(checker: #checker_name #checker_ty_generics) -> #root::export::Result<Self, Self::Error> {
+                        #try_from_body
+                    }
+                }
+            });
+        }
+
+        if self.from_staging.is_present() {
+            let from_staging_body = self.try_from_body(quote!(staging));
+            items.append_all(quote! {
+                #automatically_derived
+                impl #impl_generics #root::export::FromStaging<#checker_name #checker_ty_generics> for #ident #ty_generics #try_from_where_clause {
+                    type Error = #final_error;
+
+                    fn from_staging(staging: #checker_name #checker_ty_generics) -> #root::export::Result<Self, Self::Error> {
+                        #from_staging_body
+                    }
+                }
+            });
+        }
+
+        // A `remote` type is foreign to the deriving crate, so it cannot implement
+        // `Staged`. With `generic_error`, the type has a checker for every error type
+        // rather than a single one, and with several profiles only the `primary` one
+        // implements it. A checker that `vis` hides cannot be named by the impl.
+        if (has_try_from || self.from_staging.is_present())
+            && self.implements_staged
+            && !self.hides_checker()
+            && self.remote.is_none()
+            && self.generic_error.is_none()
+        {
+            let finalize = if has_try_from {
+                quote!(#root::export::TryFrom::try_from(staging))
+            } else {
+                quote!(#root::export::FromStaging::from_staging(staging))
+            };
+            items.append_all(quote! {
+                #automatically_derived
+                impl #impl_generics #root::export::Staged for #ident #ty_generics #try_from_where_clause {
+                    type Staging = #checker_name #checker_ty_generics;
+                    type Error = #final_error;
+
+                    fn finalize(staging: Self::Staging) -> #root::export::Result<Self, Self::Error> {
+                        #finalize
+                    }
+                }
+            });
+        }
+
+        tokens.append_all(self.scope_impls(items));
+    }
+}
+
+struct ReceiverField<'a> {
+    receiver: &'a Receiver,
+    field: &'a InputField,
+    /// Position of the field in the original struct or variant
+    index: usize,
+    /// Position of the field in the checker, which differs from `index` when earlier
+    /// fields are skipped
+    checker_index: usize,
+}
+
+impl<'a> ReceiverField<'a> {
+    fn is_skipped(&self) -> bool {
+        self.field.skip.is_some()
+    }
+
+    /// Whether the field is stored as a `Result` in the checker.
+    fn is_fallible(&self) -> bool {
+        !self.is_skipped() && !self.field.infallible.is_present()
+    }
+
+    /// The member used to access this field on the target.
+    fn member(&self) -> Member {
+        match &self.field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(syn::Index {
+                index: self.index as u32,
+                span: self.field.ty.span(),
+            }),
+        }
+    }
+
+    /// The local variable that holds the field value during finalization.
+    ///
+    /// It resolves with mixed-site hygiene, so a field named like one of the locals the
+    /// generated code declares, such as `__errors`, does not clash with it.
+    fn binding(&self) -> Ident {
+        let mut binding = match &self.field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("__field{}", self.index, span = self.field.ty.span()),
+        };
+        binding.set_span(binding.span().resolved_at(proc_macro2::Span::mixed_site()));
+        binding
+    }
+
+    /// Pattern that borrows this field for `Debug`, ignoring secret fields.
+    fn debug_pat(&self) -> Option<TokenStream> {
+        if self.field.secret.is_present() && !self.is_skipped() {
+            let member = self.checker_member();
+            let cfg = self.cfg_attrs();
+            Some(quote!(#(#cfg)* #member: _))
+        } else {
+            self.field_pat()
+        }
+    }
+
+    /// Pattern that borrows this field for `__has_errors`, ignoring fields whose errors
+    /// are never reported.
+    fn has_errors_pat(&self, count_defaults: bool) -> Option<TokenStream> {
+        if self.has_errors(count_defaults).is_some() {
+            self.field_pat()
+        } else if self.is_skipped() {
+            None
+        } else {
+            let member = self.checker_member();
+            let cfg = self.cfg_attrs();
+            Some(quote!(#(#cfg)* #member: _))
+        }
+    }
+
+    /// Expression that is true if this field's binding holds an error that would be
+    /// reported during finalization, or with `count_defaults`, any error.
+    fn has_errors(&self, count_defaults: bool) -> Option<TokenStream> {
+        if !self.is_fallible() || (self.field.default.is_some() && !count_defaults) {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let binding = self.binding();
+        // A field removed by `cfg` holds no errors.
+        let cfg = self.cfg_attrs().collect::<Vec<_>>();
+        let check = if self.child_checker().is_some() {
+            if count_defaults {
+                quote!(#binding.has_errors())
+            } else {
+                quote!(#binding.__has_errors())
+            }
+        } else if self.field.elements.is_some() {
+            quote!(#binding.iter().any(#root::export::Result::is_err))
+        } else if self.field.entries.is_some() {
+            quote!(#root::export::IntoIterator::into_iter(#binding).any(|(_, entry)| entry.is_err()))
+        } else if self.field.optional.is_present() {
+            quote!(#binding.as_ref().is_some_and(#root::export::Result::is_err))
+        } else if self.field.spanned.is_present() {
+            quote!(#binding.value.is_err())
+        } else {
+            let result = self.stored_ref(quote!(#binding), false);
+            quote!(#result.is_err())
+        };
+
+        Some(if cfg.is_empty() {
+            check
+        } else {
+            quote!({
+                #[allow(unused_mut)]
+                let mut __has_errors = false;
+                #(#cfg)*
+                {
+                    __has_errors = #check;
+                }
+                __has_errors
+            })
+        })
+    }
+
+    /// Pattern that borrows this field for `errors`, ignoring fields that cannot hold
+    /// errors.
+    fn borrow_pat(&self) -> Option<TokenStream> {
+        if self.is_fallible() {
+            self.field_pat()
+        } else if self.is_skipped() {
+            None
+        } else {
+            let member = self.checker_member();
+            let cfg = self.cfg_attrs();
+            Some(quote!(#(#cfg)* #member: _))
+        }
+    }
+
+    /// Statement that pushes a reference to each error held by this field's binding onto
+    /// `__errors`, for the checker's `errors` method.
+    fn borrow_errors(&self) -> Option<syn::Stmt> {
+        if !self.is_fallible() {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let binding = self.binding();
+        let cfg = self.cfg_attrs();
+
+        let stmt: TokenStream = if self.child_checker().is_some() {
+            quote! {
+                __errors.extend(#binding.errors());
+            }
+        } else if self.field.elements.is_some() {
+            quote! {
+                __errors.extend(#binding.iter().filter_map(|element| element.as_ref().err()));
+            }
+        } else if self.field.entries.is_some() {
+            quote! {
+                for (_, entry) in #binding {
+                    if let #root::export::Err(err) = entry {
+                        __errors.push(err);
+                    }
+                }
+            }
+        } else if self.field.optional.is_present() {
+            quote! {
+                if let #root::export::Some(#root::export::Err(err)) = #binding {
+                    __errors.push(err);
+                }
+            }
+        } else if self.field.spanned.is_present() {
+            quote! {
+                if let #root::export::Err(err) = &#binding.value {
+                    __errors.push(err);
+                }
+            }
+        } else if self.field.multiple_errors.is_present() {
+            let result = self.stored_ref(quote!(#binding), false);
+            quote! {
+                if let #root::export::Err(errors) = #result {
+                    __errors.extend(errors);
+                }
+            }
+        } else {
+            let result = self.stored_ref(quote!(#binding), false);
+            quote! {
+                if let #root::export::Err(err) = #result {
+                    __errors.push(err);
+                }
+            }
+        };
+
+        Some(parse_quote! {
+            #(#cfg)*
+            { #stmt }
+        })
+    }
+
+    /// Statement that pushes this field's name and the first error it holds onto
+    /// `__statuses`, for the checker's `field_statuses` method.
+    fn push_status(&self) -> Option<syn::Stmt> {
+        if self.is_skipped() {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let binding = self.binding();
+        let cfg = self.cfg_attrs();
+        let name = self.name();
+
+        let first_error = if !self.is_fallible() {
+            quote!(#root::export::None)
+        } else if self.child_checker().is_some() {
+            quote!(#binding.errors().next())
+        } else if self.field.elements.is_some() {
+            quote!(#binding.iter().find_map(|element| element.as_ref().err()))
+        } else if self.field.entries.is_some() {
+            quote!(#root::export::IntoIterator::into_iter(#binding).find_map(|(_, entry)| entry.as_ref().err()))
+        } else if self.field.optional.is_present() {
+            quote!(#binding.as_ref().and_then(|value| value.as_ref().err()))
+        } else if self.field.spanned.is_present() {
+            quote!(#binding.value.as_ref().err())
+        } else if self.field.multiple_errors.is_present() {
+            let result = self.stored_ref(quote!(#binding), false);
+            quote!(#result.as_ref().err().map(#root::export::AtLeastOne::first))
+        } else {
+            let result = self.stored_ref(quote!(#binding), false);
+            quote!(#result.as_ref().err())
+        };
+
+        Some(parse_quote! {
+            #(#cfg)*
+            __statuses.push((#name, #first_error.map_or(#root::export::Ok(()), #root::export::Err)));
+        })
+    }
+
+    /// The `Clone` bound needed to clone this field of the checker.
+    fn clone_bound(&self) -> Option<syn::WherePredicate> {
+        if self.is_skipped() {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let ty = self.field_type();
+        Some(parse_quote!(#ty: #root::export::Clone))
+    }
+
+    /// Initializer that clones this field's binding into a new checker.
+    fn clone_value(&self) -> Option<syn::FieldValue> {
+        if self.is_skipped() {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let member = self.checker_member();
+        let binding = self.binding();
+        let cfg = self.cfg_attrs();
+        Some(parse_quote! {
+            #(#cfg)*
+            #member: #root::export::Clone::clone(#binding)
+        })
+    }
+
+    /// The `Debug` bound needed to print this field in the checker, if it is printed.
+    fn debug_bound(&self) -> Option<syn::WherePredicate> {
+        if self.is_skipped() || self.field.secret.is_present() {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let ty = self.field_type();
+        Some(parse_quote!(#ty: #root::export::fmt::Debug))
+    }
+
+    /// The field's `cfg` attributes, which apply to all code generated for the field.
+    fn cfg_attrs(&self) -> impl Iterator<Item = &syn::Attribute> {
+        self.field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+    }
+
+    /// Attributes copied from the original field onto the checker field, other than its
+    /// doc comments, which come from `doc_attrs`.
+    fn forwarded_attrs(&self) -> impl Iterator<Item = &syn::Attribute> {
+        let forward = self.receiver.forward_attrs.as_ref();
+        self.field.attrs.iter().filter(move |attr| {
+            !attr.path().is_ident("doc")
+                && (attr.path().is_ident("cfg")
+                    || forward.is_some_and(|paths| paths.iter().any(|path| attr.path() == path)))
+        })
+    }
+
+    /// The original field's doc comments, or `fallback` if it has none, for the field's
+    /// counterpart in each generated type.
+    fn doc_attrs(&self, fallback: String) -> Vec<syn::Attribute> {
+        let docs: Vec<syn::Attribute> = self
+            .field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .cloned()
+            .collect();
+        if docs.is_empty() {
+            vec![parse_quote!(#[doc = #fallback])]
+        } else {
+            docs
+        }
+    }
+
+    /// The member used to access this field on the checker.
+    fn checker_member(&self) -> Member {
+        match &self.field.ident {
+            Some(ident) => Member::Named(self.field.rename.as_ref().unwrap_or(ident).clone()),
+            None => Member::Unnamed(syn::Index {
+                index: self.checker_index as u32,
+                span: self.field.ty.span(),
+            }),
+        }
+    }
+
+    /// Pattern that moves this field out of the checker into its binding.
+    fn field_pat(&self) -> Option<TokenStream> {
+        if self.is_skipped() {
+            return None;
+        }
+
+        let binding = self.binding();
+        let cfg = self.cfg_attrs();
+        Some(match (&self.field.ident, &self.field.rename) {
+            (Some(_), None) => quote!(#(#cfg)* #binding),
+            _ => {
+                let member = self.checker_member();
+                quote!(#(#cfg)* #member: #binding)
+            }
+        })
+    }
+
+    /// Pattern that moves this field out of the target, ignoring skipped fields.
+    fn target_pat(&self) -> TokenStream {
+        let member = self.member();
+        let cfg = self.cfg_attrs();
+        if self.is_skipped() {
+            quote!(#(#cfg)* #member: _)
+        } else {
+            let binding = self.binding();
+            quote!(#(#cfg)* #member: #binding)
+        }
+    }
+
+    /// Initializer that stores this field's value from the target in the checker.
+    fn stored_target_value(&self) -> Option<syn::FieldValue> {
+        if self.is_skipped() {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let member = self.checker_member();
+        let binding = self.binding();
+        let cfg = self.cfg_attrs();
+
+        let value = if self.field.infallible.is_present() {
+            quote!(#binding)
+        } else if self.child_checker().is_some() {
+            quote!(#root::export::Into::into(#binding))
+        } else if self.field.elements.is_some() {
+            quote! {
+                #root::export::IntoIterator::into_iter(#binding)
+                    .map(#root::export::Ok)
+                    .collect()
+            }
+        } else if self.field.entries.is_some() {
+            quote! {
+                #root::export::IntoIterator::into_iter(#binding)
+                    .map(|(key, value)| (key, #root::export::Ok(value)))
+                    .collect()
+            }
+        } else if self.field.optional.is_present() || self.field.spanned.is_present() {
+            quote!(#binding.map(#root::export::Ok))
+        } else {
+            self.store_result(quote!(#root::export::Ok(#binding)))
+        };
+
+        Some(parse_quote! {
+            #(#cfg)*
+            #member: #value
+        })
+    }
+
+    /// Visibility of the field in the checker; enum variant fields cannot have one.
+    fn vis(&self) -> Option<syn::Visibility> {
+        if self.receiver.data.is_enum() {
+            None
+        } else {
+            Some(
+                self.field
+                    .checker_vis
+                    .as_ref()
+                    .or(self.receiver.field_vis.as_ref())
+                    .cloned()
+                    .unwrap_or_else(|| parse_quote!(pub)),
+            )
+        }
+    }
+
+    /// Declaration of this field in the `patch` struct, which holds an `Option` of the
+    /// target's type.
+    fn patch_field_decl(&self) -> syn::Field {
+        let root = self.receiver.crate_root();
+        let docs = self.doc_attrs(format!(
+            " New value of `{}`, if it should change.",
+            self.name()
+        ));
+        let attrs = docs.iter().chain(self.forwarded_attrs());
+        let vis = self.vis();
+        let ty = &self.field.ty;
+
+        match self.checker_member() {
+            Member::Named(ident) => parse_quote! {
+                #(#attrs)*
+                #vis #ident: #root::export::Option<#ty>
+            },
+            Member::Unnamed(_) => parse_quote! {
+                #(#attrs)*
+                #vis #root::export::Option<#ty>
+            },
+        }
+    }
+
+    fn field_decl(&self) -> Option<syn::Field> {
+        if self.is_skipped() {
+            return None;
+        }
+
+        let docs = self.doc_attrs(format!(
+            " Value of `{}`, or the error found while staging it.",
+            self.name()
+        ));
+        let attrs = docs.iter().chain(self.forwarded_attrs());
+        let serde = self.receiver.forwarded_serde(&self.field.serde);
+        let checker_attrs = &self.field.checker_attrs;
+        let vis = self.vis();
+        let ty = self.field_type();
+
+        Some(match self.checker_member() {
+            Member::Named(ident) => parse_quote! {
+                #(#attrs)*
+                #(#[serde(#serde)])*
+                #(#checker_attrs)*
+                #vis #ident: #ty
+            },
+            Member::Unnamed(_) => parse_quote! {
+                #(#attrs)*
+                #(#[serde(#serde)])*
+                #(#checker_attrs)*
+                #vis #ty
+            },
+        })
+    }
+
+    /// The error type stored in the checker for this field.
+    fn error(&self) -> &Path {
+        self.field.error.as_ref().unwrap_or(self.receiver.error())
+    }
+
+    /// The checker type for a field whose type also derives `Staging`.
+    /// Error type held by this field in the checker: its error, or a list of them for
+    /// `multiple_errors`.
+    fn stored_error(&self) -> syn::Type {
+        let error = self.error();
+        if self.field.multiple_errors.is_present() {
+            let root = self.receiver.crate_root();
+            parse_quote!(#root::export::AtLeastOne<#error>)
+        } else {
+            parse_quote!(#error)
+        }
+    }
+
+    /// The `Result` this field holds when it is stored as a single `Result`: its value, or
+    /// the error type it stores.
+    fn result_type(&self) -> syn::Type {
+        let root = self.receiver.crate_root();
+        let error = self.stored_error();
+        match &self.field.with {
+            Some(with) => parse_quote_spanned! {with.span()=>
+                #root::export::Result<#with::Staged, #error>
+            },
+            None => {
+                let ty = &self.field.ty;
+                parse_quote_spanned! {ty.span()=>
+                    #root::export::Result<#ty, #error>
+                }
+            }
+        }
+    }
+
+    /// The `field_type` that holds this field's `Result` in the checker, if the checker
+    /// sets one and the field is stored as a single `Result`.
+    fn wrapper(&self) -> Option<&Path> {
+        if !self.is_fallible()
+            || self.child_checker().is_some()
+            || self.field.elements.is_some()
+            || self.field.entries.is_some()
+            || self.field.optional.is_present()
+            || self.field.spanned.is_present()
+        {
+            return None;
+        }
+
+        self.receiver.field_type.as_ref()
+    }
+
+    /// Expression that converts `value`, which holds this field as it is stored in the
+    /// checker, into its `Result`.
+    fn stored_result(&self, value: TokenStream) -> TokenStream {
+        if self.wrapper().is_none() {
+            return value;
+        }
+
+        let root = self.receiver.crate_root();
+        let result = self.result_type();
+        quote!(#root::export::Into::<#result>::into(#value))
+    }
+
+    /// Expression that borrows the `Result` in `value`, a reference to this field as it is
+    /// stored in the checker, mutably if `mutable` is set.
+    fn stored_ref(&self, value: TokenStream, mutable: bool) -> TokenStream {
+        if self.wrapper().is_none() {
+            return value;
+        }
+
+        let root = self.receiver.crate_root();
+        let result = self.result_type();
+        if mutable {
+            quote!(#root::export::AsMut::<#result>::as_mut(#value))
+        } else {
+            quote!(#root::export::AsRef::<#result>::as_ref(#value))
+        }
+    }
+
+    /// Expression that converts `result`, a `Result` for this field, into the type it is
+    /// stored as in the checker.
+    fn store_result(&self, result: TokenStream) -> TokenStream {
+        if self.wrapper().is_none() {
+            return result;
+        }
+
+        let root = self.receiver.crate_root();
+        quote!(#root::export::From::from(#result))
+    }
+
+    fn child_checker(&self) -> Option<syn::Type> {
+        match &self.field.nested.as_ref()?.checker {
+            Some(path) => Some(parse_quote!(#path)),
+            None => inferred_checker(&self.field.ty),
+        }
+    }
+
+    /// Name of the field on the target, as reported in errors.
+    fn name(&self) -> String {
+        match self.member() {
+            Member::Named(ident) => ident.unraw().to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        }
+    }
+
+    /// Variant of the `field_enum` for this field: its name in `PascalCase`.
+    fn variant_ident(&self) -> Ident {
+        let name = self
+            .name()
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                    .unwrap_or_default()
+            })
+            .collect::<String>();
+        Ident::new(&name, self.field.span())
+    }
+
+    /// With `field_errors`, a statement that records this field's name for each error
+    /// pushed since the last label.
+    fn label_errors(&self) -> Option<TokenStream> {
+        if !self.receiver.field_errors.is_present() {
+            return None;
+        }
+
+        let name = self.name();
+        Some(quote!(__fields.resize(__errors.len(), #name);))
+    }
+
+    /// Initial value of this field in the checker created by `new`.
+    fn initial_value(&self) -> Option<syn::FieldValue> {
+        if self.is_skipped() {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let member = self.checker_member();
+        let missing = match (&self.receiver.builder, &self.receiver.missing) {
+            (Some(path), _) => {
+                let name = self.name();
+                quote!(#path(#name))
+            }
+            (None, Some(expr)) => quote!(#expr),
+            (None, None) => unreachable!("`new` requires `builder` or `missing`"),
+        };
+        let missing = quote!(#root::export::Err(#root::export::Into::into(#missing)));
+
+        let value = if self.field.infallible.is_present()
+            || self.field.elements.is_some()
+            || self.field.entries.is_some()
+        {
+            quote!(#root::export::Default::default())
+        } else if let Some(checker) = self.child_checker() {
+            quote!(<#checker>::new())
+        } else if self.field.optional.is_present() {
+            quote!(#root::export::None)
+        } else if self.field.spanned.is_present() {
+            quote!(#root::export::Spanned::new(#missing, #root::export::Default::default()))
+        } else if self.field.multiple_errors.is_present() {
+            self.store_result(quote!(#missing.map_err(#root::export::AtLeastOne::new)))
+        } else {
+            self.store_result(missing)
+        };
+
+        let cfg = self.cfg_attrs();
+        Some(parse_quote! {
+            #(#cfg)*
+            #member: #value
+        })
+    }
+
+    /// Name of the checker field without any raw identifier prefix, used to name its
+    /// setters.
+    /// Key of this field in a document read by the checker's `Deserialize` impl: its
+    /// `serde(rename)`, or its name in the checker in the case of `rename_all`.
+    fn deserialize_key(&self, rename_all: Option<&str>) -> Option<String> {
+        if self.is_skipped() {
+            return None;
+        }
+
+        let options = SerdeOptions::parse(&self.field.serde, &["rename", "alias"]).ok()?;
+        if let Some(rename) = options.rename {
+            return Some(rename);
+        }
+
+        let name = match self.checker_member() {
+            Member::Named(ident) => ident.unraw().to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        Some(
+            rename_all
+                .and_then(|case| rename_case(&name, case))
+                .unwrap_or(name),
+        )
+    }
+
+    /// Type this field's value is deserialized as by the checker's `Deserialize` impl.
+    fn deserialize_type(&self) -> Option<syn::Type> {
+        if self.is_skipped() {
+            None
+        } else if let Some(checker) = self.child_checker() {
+            Some(checker)
+        } else if let Some(with) = &self.field.with {
+            Some(parse_quote!(#with::Staged))
+        } else {
+            Some(self.field.ty.clone())
+        }
+    }
+
+    /// Bound the checker's `Deserialize` impl needs to read this field, for generic
+    /// checkers.
+    fn deserialize_bound(&self) -> Option<syn::WherePredicate> {
+        let ty = self.deserialize_type()?;
+        let root = self.receiver.crate_root();
+        Some(parse_quote!(#ty: #root::export::serde::Deserialize<'de>))
+    }
+
+    /// Match arm of the checker's `Deserialize` impl that reads this field's value from
+    /// the map. A value that is not a valid `T` is stored as the field's error, converted
+    /// from a `DeserializeError` with `Into`; fields without an error of their own, such
+    /// as `infallible` and `nested` fields, fail deserialization instead.
+    fn deserialize_arm(&self, rename_all: Option<&str>) -> Option<TokenStream> {
+        let key = self.deserialize_key(rename_all)?;
+        let ty = self.deserialize_type()?;
+        let aliases = SerdeOptions::parse(&self.field.serde, &["rename", "alias"])
+            .map(|options| options.aliases)
+            .unwrap_or_default();
+        let root = self.receiver.crate_root();
+        let member = self.checker_member();
+        let name = self.name();
+        let cfg = self.cfg_attrs();
+
+        let value = if self.field.infallible.is_present() || self.child_checker().is_some() {
+            quote!(#root::export::serde::de::MapAccess::next_value::<#ty>(map)?)
+        } else {
+            let value = quote!(#root::export::deserialize_value::<#ty, _>(map, #name)?);
+            if self.field.optional.is_present() {
+                quote!(#value.map_err(#root::export::Into::into).transpose())
+            } else if self.field.multiple_errors.is_present() {
+                self.store_result(quote! {
+                    #value.map_err(|err| #root::export::AtLeastOne::new(#root::export::Into::into(err)))
+                })
+            } else {
+                self.store_result(quote!(#value.map_err(#root::export::Into::into)))
+            }
+        };
+
+        Some(quote! {
+            #(#cfg)*
+            #key #(| #aliases)* => {
+                self.#member = #value;
+                #root::export::Ok(#root::export::Some(#key))
+            }
+        })
+    }
+
+    fn setter_suffix(&self) -> String {
+        match self.checker_member() {
+            Member::Named(ident) => ident.unraw().to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        }
+    }
+
+    /// Setter that stores a successful value in this field.
+    fn setter(&self) -> Option<syn::ImplItemFn> {
+        if self.is_skipped() {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let vis = self.receiver.checker_vis();
+        let member = self.checker_member();
+        let name = self.setter_suffix();
+        let setter = format_ident!("set_{}", name, span = self.field.ty.span());
+        let doc = format!(" Set `{}` to a successful value.", name);
+        let cfg = self.cfg_attrs();
+
+        let ty = &self.field.ty;
+        let (input, value): (syn::Type, TokenStream) = if self.field.infallible.is_present() {
+            (ty.clone(), quote!(value))
+        } else if let Some(checker) = self.child_checker() {
+            (checker, quote!(value))
+        } else if self.field.elements.is_some() {
+            (
+                ty.clone(),
+                quote! {
+                    #root::export::IntoIterator::into_iter(value)
+                        .map(#root::export::Ok)
+                        .collect()
+                },
+            )
+        } else if self.field.entries.is_some() {
+            (
+                ty.clone(),
+                quote! {
+                    #root::export::IntoIterator::into_iter(value)
+                        .map(|(key, value)| (key, #root::export::Ok(value)))
+                        .collect()
+                },
+            )
+        } else if self.field.optional.is_present() {
+            let inner = type_arguments(ty)[0];
+            (
+                inner.clone(),
+                quote!(#root::export::Some(#root::export::Ok(value))),
+            )
+        } else if self.field.spanned.is_present() {
+            (ty.clone(), quote!(value.map(#root::export::Ok)))
+        } else if let Some(with) = &self.field.with {
+            (
+                parse_quote!(#with::Staged),
+                self.store_result(quote!(#root::export::Ok(value))),
+            )
+        } else {
+            (
+                ty.clone(),
+                self.store_result(quote!(#root::export::Ok(value))),
+            )
+        };
+
+        let (param, convert) = if self.field.into.is_present() {
+            (
+                quote!(impl #root::export::Into<#input>),
+                Some(quote!(let value: #input = #root::export::Into::into(value);)),
+            )
+        } else {
+            (quote!(#input), None)
+        };
+
+        Some(parse_quote! {
+            #[doc = #doc]
+            #(#cfg)*
+            #vis fn #setter(&mut self, value: #param) -> &mut Self {
+                #convert
+                self.#member = #value;
+                self
+            }
+        })
+    }
+
+    /// Setter that stores a `Result` in this field, for fields whose checker storage
+    /// holds a single `Result`.
+    fn try_setter(&self) -> Option<syn::ImplItemFn> {
+        if !self.is_fallible()
+            || self.child_checker().is_some()
+            || self.field.elements.is_some()
+            || self.field.entries.is_some()
+        {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let vis = self.receiver.checker_vis();
+        let member = self.checker_member();
+        let name = self.setter_suffix();
+        let setter = format_ident!("try_set_{}", name, span = self.field.ty.span());
+        let doc = format!(" Set `{}` to a value or an error.", name);
+        let cfg = self.cfg_attrs();
+        let error = self.error();
+
+        let ty = &self.field.ty;
+        let input: syn::Type =
+            if self.field.optional.is_present() || self.field.spanned.is_present() {
+                type_arguments(ty)[0].clone()
+            } else if let Some(with) = &self.field.with {
+                parse_quote!(#with::Staged)
+            } else {
+                ty.clone()
+            };
+
+        let (input, convert) = if self.field.into.is_present() {
+            (
+                quote!(impl #root::export::Into<#input>),
+                Some(quote!(.map(#root::export::Into::into))),
+            )
+        } else {
+            (quote!(#input), None)
+        };
+
+        let (param, value): (TokenStream, TokenStream) = if self.field.spanned.is_present() {
+            let param = map_type_argument(
+                ty,
+                0,
+                |_| parse_quote!(#root::export::Result<#input, #error>),
+            );
+            (
+                param.into_token_stream(),
+                quote!(value.map(|value| value #convert)),
+            )
+        } else if self.field.optional.is_present() {
+            (
+                quote!(#root::export::Result<#input, #error>),
+                quote!(#root::export::Some(value #convert)),
+            )
+        } else {
+            let error = self.stored_error();
+            (
+                quote!(#root::export::Result<#input, #error>),
+                self.store_result(quote!(value #convert)),
+            )
+        };
+
+        Some(parse_quote! {
+            #[doc = #doc]
+            #(#cfg)*
+            #vis fn #setter(&mut self, value: #param) -> &mut Self {
+                self.#member = #value;
+                self
+            }
+        })
+    }
+
+    /// Statement that writes this field's binding onto `target` if it holds a successful
+    /// value, for the checker's `apply_to` method.
+    fn apply_update(&self) -> Option<syn::Stmt> {
+        if self.is_skipped() {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let binding = self.binding();
+        let member = self.member();
+        let cfg = self.cfg_attrs();
+
+        let stmt = if self.field.infallible.is_present() {
+            quote!(target.#member = #binding;)
+        } else if self.child_checker().is_some() {
+            quote!(#binding.apply_to(&mut target.#member);)
+        } else if self.field.elements.is_some() {
+            quote! {
+                if let #root::export::Ok(values) = #binding
+                    .into_iter()
+                    .collect::<#root::export::Result<_, _>>()
+                {
+                    target.#member = values;
+                }
+            }
+        } else if self.field.entries.is_some() {
+            quote! {
+                for (key, entry) in #binding {
+                    if let #root::export::Ok(value) = entry {
+                        target.#member.insert(key, value);
+                    }
+                }
+            }
+        } else if self.field.optional.is_present() {
+            quote! {
+                if let #root::export::Some(#root::export::Ok(value)) = #binding {
+                    target.#member = #root::export::Some(value);
+                }
+            }
+        } else if self.field.spanned.is_present() {
+            quote! {
+                if let #root::export::Ok(value) = #binding.transpose() {
+                    target.#member = value;
+                }
+            }
+        } else {
+            let result = self.stored_result(quote!(#binding));
+            quote! {
+                if let #root::export::Ok(value) = #result {
+                    target.#member = value;
+                }
+            }
+        };
+
+        Some(parse_quote! {
+            #(#cfg)*
+            { #stmt }
+        })
+    }
+
+    /// Value of this field in the checker returned by `merge`, built from `self` and
+    /// `other`. When both are equally good, the one chosen by `prefer` is kept.
+    fn merged_value(&self, prefer: Prefer) -> Option<syn::FieldValue> {
+        if self.is_skipped() {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let member = self.checker_member();
+        let cfg = self.cfg_attrs();
+        let (preferred, fallback) = match prefer {
+            Prefer::Current => (quote!(__self), quote!(__other)),
+            Prefer::Other => (quote!(__other), quote!(__self)),
+        };
+
+        // Rank each value so that the better one can be kept.
+        let rank = |value: &TokenStream| -> Option<TokenStream> {
+            if !self.is_fallible() || self.child_checker().is_some() || self.field.entries.is_some()
+            {
+                None
+            } else if self.field.elements.is_some() {
+                Some(quote!(u8::from(#value.iter().all(#root::export::Result::is_ok))))
+            } else if self.field.optional.is_present() {
+                Some(quote!(#value.as_ref().map_or(0, |value| 1 + u8::from(value.is_ok()))))
+            } else if self.field.spanned.is_present() {
+                Some(quote!(u8::from(#value.value.is_ok())))
+            } else {
+                let result = self.stored_ref(quote!(&#value), false);
+                Some(quote!(u8::from(#root::export::Result::is_ok(#result))))
+            }
+        };
+
+        let value = if self.child_checker().is_some() {
+            quote!(__self.merge(__other))
+        } else if self.field.entries.is_some() {
+            quote! {
+                let mut merged = #fallback;
+                for (key, value) in #preferred {
+                    let value = match merged.remove(&key) {
+                        #root::export::Some(existing) if existing.is_ok() && value.is_err() => existing,
+                        _ => value,
+                    };
+                    merged.insert(key, value);
+                }
+                merged
+            }
+        } else if let (Some(preferred_rank), Some(fallback_rank)) =
+            (rank(&preferred), rank(&fallback))
+        {
+            quote! {
+                if #fallback_rank > #preferred_rank {
+                    #fallback
+                } else {
+                    #preferred
+                }
+            }
+        } else {
+            quote!(#preferred)
+        };
+
+        Some(parse_quote! {
+            #(#cfg)*
+            #member: {
+                let (__self, __other) = (self.#member, other.#member);
+                #value
+            }
+        })
+    }
+
+    /// Type and value of a borrow of what this field holds in `self`, mutably if `mutable`
+    /// is set and for `lifetime` if given.
+    ///
+    /// Fields stored as a `Result` (or an `Option` of one) are borrowed as a `Result` of
+    /// references; other fields are borrowed as they are stored.
+    fn borrowed(
+        &self,
+        lifetime: Option<&syn::Lifetime>,
+        mutable: bool,
+    ) -> (TokenStream, TokenStream) {
+        let root = self.receiver.crate_root();
+        let member = self.checker_member();
+        let error = self.error();
+        let (reference, borrow, as_ref) = if mutable {
+            (quote!(&#lifetime mut), quote!(&mut), quote!(as_mut))
+        } else {
+            (quote!(&#lifetime), quote!(&), quote!(as_ref))
+        };
+
+        let ty = &self.field.ty;
+        let stored_as_result = self.is_fallible()
+            && self.child_checker().is_none()
+            && self.field.elements.is_none()
+            && self.field.entries.is_none()
+            && !self.field.spanned.is_present();
+        if !stored_as_result {
+            let stored = self.field_type();
+            (quote!(#reference #stored), quote!(#borrow self.#member))
+        } else if self.field.optional.is_present() {
+            let inner = type_arguments(ty)[0];
+            (
+                quote! {
+                    #root::export::Option<
+                        #root::export::Result<#reference #inner, #reference #error>,
+                    >
+                },
+                quote!(self.#member.#as_ref().map(#root::export::Result::#as_ref)),
+            )
+        } else {
+            let value: syn::Type = match &self.field.with {
+                Some(with) => parse_quote!(#with::Staged),
+                None => ty.clone(),
+            };
+            let error = self.stored_error();
+            let result = self.stored_ref(quote!(#borrow self.#member), mutable);
+            (
+                quote!(#root::export::Result<#reference #value, #reference #error>),
+                quote!(#root::export::Result::#as_ref(#result)),
+            )
+        }
+    }
+
+    /// Method that borrows what this field holds, mutably if `mutable` is set.
+    fn accessor(&self, mutable: bool) -> Option<syn::ImplItemFn> {
+        if self.is_skipped() {
+            return None;
+        }
+
+        let vis = self.receiver.checker_vis();
+        let member = self.checker_member();
+        let name = self.setter_suffix();
+        let mut accessor = match (&member, mutable) {
+            (Member::Named(ident), false) => ident.clone(),
+            (Member::Named(_), true) => format_ident!("{}_mut", name),
+            (Member::Unnamed(_), false) => format_ident!("field_{}", name),
+            (Member::Unnamed(_), true) => format_ident!("field_{}_mut", name),
+        };
+        accessor.set_span(self.field.span());
+        let doc = if mutable {
+            format!(" Mutably borrow what `{}` holds.", name)
+        } else {
+            format!(" Borrow what `{}` holds.", name)
+        };
+        let cfg = self.cfg_attrs();
+        let receiver = if mutable {
+            quote!(&mut self)
+        } else {
+            quote!(&self)
+        };
+        let (output, body) = self.borrowed(None, mutable);
+
+        Some(parse_quote! {
+            #[doc = #doc]
+            #(#cfg)*
+            #vis fn #accessor(#receiver) -> #output {
+                #body
+            }
+        })
+    }
+
+    fn field_type(&self) -> syn::Type {
+        let ty = &self.field.ty;
+        if self.field.infallible.is_present() {
+            return ty.clone();
+        }
+
+        if let Some(checker) = self.child_checker() {
+            return checker;
+        }
+
+        let error = self.error();
+        let root = self.receiver.crate_root();
+        if self.field.elements.is_some() {
+            let element = type_arguments(ty)[0];
+            return parse_quote_spanned! {ty.span()=>
+                #root::export::Vec<#root::export::Result<#element, #error>>
+            };
+        }
+
+        if self.field.optional.is_present() {
+            let inner = type_arguments(ty)[0];
+            return parse_quote_spanned! {ty.span()=>
+                #root::export::Option<#root::export::Result<#inner, #error>>
+            };
+        }
+
+        if self.field.entries.is_some() {
+            // Keep the map type (and any hasher parameter), wrapping only the value type.
+            return map_type_argument(
+                ty,
+                1,
+                |value| parse_quote!(#root::export::Result<#value, #error>),
+            );
+        }
+
+        if self.field.spanned.is_present() {
+            return map_type_argument(
+                ty,
+                0,
+                |value| parse_quote!(#root::export::Result<#value, #error>),
+            );
+        }
+
+        let result = self.result_type();
+        let Some(wrapper) = self.wrapper() else {
+            return result;
+        };
+        let value: syn::Type = match &self.field.with {
+            Some(with) => parse_quote!(#with::Staged),
+            None => ty.clone(),
+        };
+        let error = self.stored_error();
+        parse_quote_spanned! {wrapper.span()=>
+            #wrapper<#value, #error>
+        }
+    }
+
+    /// Expression that converts every error in `value`, which holds this field as it is
+    /// stored in the checker, with the `map_errors` function `f`.
+    fn map_errors(&self, value: TokenStream) -> TokenStream {
+        let root = self.receiver.crate_root();
+        if !self.is_fallible() {
+            return value;
+        }
+
+        if self.child_checker().is_some() {
+            return quote!(#value.map_errors(&f));
+        }
+
+        let map_result = if self.field.multiple_errors.is_present() {
+            quote! {
+                |result| #root::export::Result::map_err(result, |errors| {
+                    #root::export::AtLeastOne::map(errors, &f)
+                })
+            }
+        } else {
+            quote!(|result| #root::export::Result::map_err(result, &f))
+        };
+
+        if self.field.elements.is_some() {
+            quote! {
+                #root::export::Iterator::collect(#root::export::Iterator::map(
+                    #root::export::IntoIterator::into_iter(#value),
+                    #map_result,
+                ))
+            }
+        } else if self.field.entries.is_some() {
+            quote! {
+                #root::export::Iterator::collect(#root::export::Iterator::map(
+                    #root::export::IntoIterator::into_iter(#value),
+                    |(key, value)| (key, (#map_result)(value)),
+                ))
+            }
+        } else if self.field.optional.is_present() {
+            quote!(#root::export::Option::map(#value, #map_result))
+        } else {
+            let result = self.stored_result(value);
+            self.store_result(quote!((#map_result)(#result)))
+        }
+    }
+
+    /// Statement that shadows this field's binding with its value, recording the errors
+    /// it holds.
+    ///
+    /// With `Finalize::FirstError`, a field that holds an error returns it right away and
+    /// the binding holds the value itself. With `Finalize::NonEmpty`, the binding holds
+    /// the value or the field's errors in an `AtLeastOne`. Otherwise its errors are pushed
+    /// to `__errors` and the binding holds `None`. Before pushing, `__errors` reserves room
+    /// for one error from each of the `remaining` checks, counting this field, so it is
+    /// usually allocated once however many fields fail.
+    fn take_error(&self, mode: Finalize, remaining: usize) -> Option<syn::Stmt> {
+        let mut stmt = self.take_error_inner(mode, remaining)?;
+        if let syn::Stmt::Local(local) = &mut stmt {
+            local.attrs.extend(self.cfg_attrs().cloned());
+        }
+        Some(stmt)
+    }
+
+    fn take_error_inner(&self, mode: Finalize, remaining: usize) -> Option<syn::Stmt> {
+        if !self.is_fallible() {
+            return None;
+        }
+
+        let binding = self.binding();
+
+        let root = self.receiver.crate_root();
+        let first = mode == Finalize::FirstError;
+        let non_empty = mode == Finalize::NonEmpty;
+        let reserve = self
+            .receiver
+            .error_storage
+            .is_none()
+            .then(|| quote!(__errors.reserve(#remaining);));
+        let some = |value: TokenStream| {
+            if first {
+                value
+            } else if non_empty {
+                quote!(#root::export::Ok(#value))
+            } else {
+                quote!(#root::export::Some(#value))
+            }
+        };
+        let fail = |err: TokenStream| {
+            if first {
+                let err = self.first_error(err);
+                quote!(return #root::export::Err(#err))
+            } else if non_empty {
+                let err = self.first_error(err);
+                quote!(#root::export::Err(#root::export::AtLeastOne::new(#err)))
+            } else {
+                let push = self.receiver.push_error(err);
+                quote!({
+                    #reserve
+                    #push
+                    #root::export::None
+                })
+            }
+        };
+
+        if self.field.merges_errors() {
+            if first {
+                let err = self.first_error(quote!(#root::export::Into::into(err)));
+                return Some(parse_quote! {
+                    let #binding = match #binding.__finalize_first() {
+                        #root::export::Result::Ok(value) => value,
+                        #root::export::Result::Err(err) => return #root::export::Err(#err),
+                    };
+                });
+            }
+
+            if non_empty {
+                let err = self.first_error(quote!(#root::export::Into::into(err)));
+                return Some(parse_quote! {
+                    let #binding = #root::export::Result::map_err(
+                        #binding.__finalize_non_empty(),
+                        |errors| #root::export::AtLeastOne::map(errors, |err| #err),
+                    );
+                });
+            }
+
+            let extend = self
+                .receiver
+                .extend_errors(quote!(errors.into_iter().map(#root::export::Into::into)));
+            return Some(parse_quote! {
+                let #binding = match #binding.into_result() {
+                    #root::export::Result::Ok(value) => #root::export::Some(value),
+                    #root::export::Result::Err(errors) => {
+                        #reserve
+                        #extend
+                        #root::export::None
+                    }
+                };
+            });
+        }
+
+        if self.field.nested.is_some() {
+            let ty = &self.field.ty;
+            let on_ok = some(quote!(value));
+            let on_err = fail(quote!(#root::export::Into::into(err)));
+            return Some(parse_quote! {
+                let #binding = match <#ty as #root::export::TryFrom<_>>::try_from(#binding) {
+                    #root::export::Result::Ok(value) => #on_ok,
+                    #root::export::Result::Err(err) => #on_err,
+                };
+            });
+        }
+
+        if self.field.elements.is_some() || self.field.entries.is_some() {
+            let (iter, err) = match (&self.field.elements, &self.field.entries) {
+                (Some(Override::Explicit(path)), _) => (
+                    quote!((__index, element) in #binding.into_iter().enumerate()),
+                    quote!(#path(__index, err)),
+                ),
+                (Some(Override::Inherit), _) => (
+                    quote!((__index, element) in #binding.into_iter().enumerate()),
+                    quote!(err),
+                ),
+                (None, Some(Override::Explicit(path))) => (
+                    quote!((__key, element) in #binding),
+                    quote!(#path(&__key, err)),
+                ),
+                (None, _) => (quote!((__key, element) in #binding), quote!(err)),
+            };
+            // A bare `elements` keeps each element's index by wrapping its error.
+            let err_value = if let Some(Override::Inherit) = &self.field.elements {
+                let source = match &self.field.map_err {
+                    Some(map_err) => quote!(#map_err(#err)),
+                    None => err,
+                };
+                quote!(#root::export::Into::into(#root::export::ElementError::new(__index, #source)))
+            } else {
+                self.err_value(err)
+            };
+            let push = self.receiver.push_error(err_value.clone());
+            let error = self.receiver.error();
+
+            let (values, pattern, value) = if self.field.elements.is_some() {
+                (
+                    quote!(#binding.into_iter().enumerate()),
+                    quote!((__index, element)),
+                    quote!(value),
+                )
+            } else {
+                (
+                    quote!(#binding.into_iter()),
+                    quote!((__key, element)),
+                    quote!((__key, value)),
+                )
+            };
+
+            // Collecting into a `Result` stops at the first error, so the values are only
+            // gathered into the field's own collection type.
+            if first {
+                let err = self.first_error(quote!(err));
+                return Some(parse_quote! {
+                    let #binding = match #values
+                        .map(|#pattern| match element {
+                            #root::export::Result::Ok(value) => #root::export::Ok(#value),
+                            #root::export::Result::Err(err) => #root::export::Err(#err_value),
+                        })
+                        .collect::<#root::export::Result<_, #error>>()
+                    {
+                        #root::export::Result::Ok(value) => value,
+                        #root::export::Result::Err(err) => return #root::export::Err(#err),
+                    };
+                });
+            }
+
+            // Keep the values until an element fails, then only its errors, so the errors
+            // are never gathered into a list that could be empty.
+            if non_empty {
+                let err = self.first_error(err_value);
+                return Some(parse_quote! {
+                    let #binding = #root::export::Result::map(
+                        #root::export::Iterator::fold(
+                            #values,
+                            #root::export::Ok(#root::export::Vec::new()),
+                            |__result, #pattern| match (__result, element) {
+                                (#root::export::Ok(mut __values), #root::export::Ok(value)) => {
+                                    __values.push(#value);
+                                    #root::export::Ok(__values)
+                                }
+                                (#root::export::Ok(_), #root::export::Err(err)) => {
+                                    #root::export::Err(#root::export::AtLeastOne::new(#err))
+                                }
+                                (#root::export::Err(__errors), #root::export::Ok(_)) => {
+                                    #root::export::Err(__errors)
+                                }
+                                (#root::export::Err(mut __errors), #root::export::Err(err)) => {
+                                    __errors.push(#err);
+                                    #root::export::Err(__errors)
+                                }
+                            },
+                        ),
+                        |__values| #root::export::Iterator::collect(
+                            #root::export::IntoIterator::into_iter(__values),
+                        ),
+                    );
+                });
+            }
+
+            // Check every element before taking any, so a field without errors is
+            // collected straight into its own type and one with errors only records them.
+            let (all_ok, values) = if self.field.elements.is_some() {
+                (
+                    quote!(#binding.iter().all(#root::export::Result::is_ok)),
+                    quote!(#binding.into_iter().filter_map(#root::export::Result::ok)),
+                )
+            } else {
+                (
+                    quote!(#binding.iter().all(|(_, entry)| entry.is_ok())),
+                    quote! {
+                        #binding
+                            .into_iter()
+                            .filter_map(|(__key, entry)| entry.ok().map(|value| (__key, value)))
+                    },
+                )
+            };
+            return Some(parse_quote! {
+                let #binding = if #all_ok {
+                    #root::export::Some(#values.collect())
+                } else {
+                    #reserve
+                    for #iter {
+                        if let #root::export::Result::Err(err) = element {
+                            #push
+                        }
+                    }
+                    #root::export::None
+                };
+            });
+        }
+
+        if self.field.spanned.is_present() {
+            let on_ok = some(quote!(value));
+            let on_err = fail(self.err_value(quote!(err)));
+            return Some(parse_quote! {
+                let #binding = match #binding.transpose() {
+                    #root::export::Result::Ok(value) => #on_ok,
+                    #root::export::Result::Err(err) => #on_err,
+                };
+            });
+        }
+
+        if self.field.optional.is_present() {
+            let on_none = some(quote!(#root::export::Option::None));
+            let on_ok = some(quote!(#root::export::Option::Some(value)));
+            let on_err = fail(self.err_value(quote!(err)));
+            return Some(parse_quote! {
+                let #binding = match #binding {
+                    #root::export::Option::None => #on_none,
+                    #root::export::Option::Some(#root::export::Result::Ok(value)) => #on_ok,
+                    #root::export::Option::Some(#root::export::Result::Err(err)) => #on_err,
+                };
+            });
+        }
+
+        let err_value = self.err_value(quote!(err));
+        let on_err = match &self.field.default {
+            Some(Override::Explicit(expr)) => some(quote!(#expr)),
+            Some(Override::Inherit) => some(quote!(#root::export::Default::default())),
+            // Only the first of the field's errors is reported, so it is taken from the
+            // `AtLeastOne` directly.
+            None if self.field.multiple_errors.is_present() && first => {
+                let err = self.first_error(err_value);
+                quote!({
+                    let err = err.into_first();
+                    return #root::export::Err(#err);
+                })
+            }
+            None if self.field.multiple_errors.is_present() && non_empty => {
+                let err = self.first_error(err_value);
+                quote!(#root::export::Err(#root::export::AtLeastOne::map(err, |err| #err)))
+            }
+            None if self.field.multiple_errors.is_present() => {
+                let extend = self
+                    .receiver
+                    .extend_errors(quote!(err.into_iter().map(|err| #err_value)));
+                quote! {{
+                    #reserve
+                    #extend
+                    #root::export::None
+                }}
+            }
+            None => fail(err_value),
+        };
+        let err = if self.field.default.is_some() {
+            quote!(_)
+        } else {
+            quote!(err)
+        };
+        let with = self
+            .field
+            .with
+            .as_ref()
+            .map(|path| quote!(.and_then(#path::finalize)));
+        let validate = self.field.validate.as_ref().map(|path| {
+            let ctx = self.receiver.context.as_ref().map(|_| quote!(, __ctx));
+            quote! {
+                .and_then(|value| {
+                    #path(&value #ctx)?;
+                    #root::export::Ok(value)
+                })
+            }
+        });
+        let or_else = self
+            .field
+            .or_else
+            .as_ref()
+            .map(|path| quote!(.or_else(#path)));
+        let on_ok = some(quote!(value));
+        let result = self.stored_result(quote!(#binding));
+
+        Some(parse_quote! {
+            let #binding = match #result #with #validate #or_else {
+                #root::export::Result::Ok(value) => #on_ok,
+                #root::export::Result::Err(#err) => #on_err,
+            };
+        })
+    }
+
+    /// Statement that shadows this field's binding with the value it holds once its
+    /// errors have been taken, or leaves the `'finish` block to report them if it has
+    /// none.
+    fn take_value(&self) -> Option<syn::Stmt> {
+        if !self.is_fallible() {
+            return None;
+        }
+
+        let root = self.receiver.crate_root();
+        let binding = self.binding();
+        let cfg = self.cfg_attrs();
+        Some(parse_quote! {
+            #(#cfg)*
+            let #root::export::Some(#binding) = #binding else {
+                break 'finish;
+            };
+        })
+    }
+
+    /// The error returned for `err` when finalizing stops at the first error, labelled
+    /// with the field's name if `field_errors` is set.
+    fn first_error(&self, err: TokenStream) -> TokenStream {
+        if !self.receiver.field_errors.is_present() {
+            return err;
+        }
+
+        let root = self.receiver.crate_root();
+        let name = self.name();
+        quote!(#root::export::FieldError::new(#name, #err))
+    }
+
+    /// Whether `TakeValues` checks this field for errors before taking its value. Fields
+    /// with a `default` are taken whatever they hold.
+    fn is_checked(&self) -> bool {
+        self.is_fallible() && self.field.default.is_none()
+    }
+
+    /// Type of this field's value once it has been taken by `TakeValues`.
+    ///
+    /// `elements` and `entries` fields, and fields that are not checked, keep their stored
+    /// type, since they are taken whole.
+    fn taken_type(&self) -> syn::Type {
+        let root = self.receiver.crate_root();
+        if let Some(checker) = self.child_checker() {
+            parse_quote!(<#checker as #root::export::TakeValues>::Values)
+        } else if !self.is_checked()
+            || self.field.elements.is_some()
+            || self.field.entries.is_some()
+        {
+            self.field_type()
+        } else {
+            self.field.ty.clone()
+        }
+    }
+
+    /// Expression that takes the value out of `value`, which holds this field as it is
+    /// stored in the checker, or returns it unchanged in `Err` if it holds an error.
+    fn take_checked(&self, value: TokenStream) -> TokenStream {
+        let root = self.receiver.crate_root();
+        if let Some(checker) = self.child_checker() {
+            quote!(<#checker as #root::export::TakeValues>::take_values(#value))
+        } else if self.field.elements.is_some() {
+            quote! {
+                if #value.iter().all(#root::export::Result::is_ok) {
+                    #root::export::Ok(#value)
+                } else {
+                    #root::export::Err(#value)
+                }
+            }
+        } else if self.field.entries.is_some() {
+            quote! {
+                if #root::export::IntoIterator::into_iter(&#value).all(|(_, entry)| entry.is_ok()) {
+                    #root::export::Ok(#value)
+                } else {
+                    #root::export::Err(#value)
+                }
+            }
+        } else if self.field.optional.is_present() {
+            quote! {
+                match #value {
+                    #root::export::None => #root::export::Ok(#root::export::None),
+                    #root::export::Some(#root::export::Ok(value)) => {
+                        #root::export::Ok(#root::export::Some(value))
+                    }
+                    #root::export::Some(#root::export::Err(err)) => {
+                        #root::export::Err(#root::export::Some(#root::export::Err(err)))
+                    }
+                }
+            }
+        } else if self.field.spanned.is_present() {
+            quote! {
+                #root::export::Result::map_err(#value.transpose(), |err| {
+                    #root::export::Spanned::map(err, #root::export::Err)
+                })
+            }
+        } else if self.wrapper().is_some() {
+            let result = self.stored_result(value);
+            let stored = self.store_result(quote!(#root::export::Err(err)));
+            quote!(#root::export::Result::map_err(#result, |err| #stored))
+        } else {
+            quote!(#root::export::Result::map_err(#value, #root::export::Err))
+        }
+    }
+
+    /// Expression that stores `value`, this field's taken value, back in the checker.
+    fn restore_value(&self, value: TokenStream) -> TokenStream {
+        let root = self.receiver.crate_root();
+        if let Some(checker) = self.child_checker() {
+            quote!(<#checker as #root::export::TakeValues>::restore(#value))
+        } else if !self.is_checked()
+            || self.field.elements.is_some()
+            || self.field.entries.is_some()
+        {
+            value
+        } else if self.field.optional.is_present() {
+            quote!(#root::export::Option::map(#value, #root::export::Ok))
+        } else if self.field.spanned.is_present() {
+            quote!(#root::export::Spanned::map(#value, #root::export::Ok))
+        } else {
+            self.store_result(quote!(#root::export::Ok(#value)))
+        }
+    }
+
+    /// Expression that builds this field's value on the target from `value`, its taken
+    /// value.
+    fn build_value(&self, value: TokenStream) -> TokenStream {
+        let root = self.receiver.crate_root();
+        if let Some(checker) = self.child_checker() {
+            return quote!(<#checker as #root::export::TakeValues>::build(#value));
+        }
+
+        let default = match &self.field.default {
+            Some(Override::Explicit(expr)) => quote!(#expr),
+            Some(Override::Inherit) => quote!(#root::export::Default::default()),
+            None if self.field.elements.is_some() => {
+                return quote! {
+                    #root::export::Iterator::collect(#root::export::Iterator::filter_map(
+                        #root::export::IntoIterator::into_iter(#value),
+                        #root::export::Result::ok,
+                    ))
+                };
+            }
+            None if self.field.entries.is_some() => {
+                return quote! {
+                    #root::export::Iterator::collect(#root::export::Iterator::filter_map(
+                        #root::export::IntoIterator::into_iter(#value),
+                        |(key, entry)| #root::export::Result::ok(entry).map(|value| (key, value)),
+                    ))
+                };
+            }
+            None => return value,
+        };
+        let result = self.stored_result(value);
+        quote! {
+            match #result {
+                #root::export::Ok(value) => value,
+                #root::export::Err(_) => #default,
+            }
+        }
+    }
+
+    /// Statement that awaits this field's `validate_async` function on its value, if it
+    /// has one, and stores the error in the field if it fails.
+    fn validate_async(&self) -> Option<TokenStream> {
+        let path = self.field.validate_async.as_ref()?;
+        let root = self.receiver.crate_root();
+        let member = self.checker_member();
+        let ctx = self.receiver.context_arg().map(|ctx| quote!(, #ctx));
+        let stored = self.stored_ref(quote!(&self.#member), false);
+        let failed = self.store_result(quote!(#root::export::Err(#root::export::Into::into(err))));
+        Some(quote! {
+            if let #root::export::Ok(value) = #stored {
+                let result = #path(value #ctx).await;
+                if let #root::export::Err(err) = result {
+                    self.#member = #failed;
+                }
+            }
+        })
+    }
+
+    /// Expression that is true if this field's value was provided, for use after its
+    /// errors have been taken. An `optional` field holding `None` is not provided.
+    ///
+    /// With `Finalize::FirstError`, a field that failed has already returned, so its
+    /// binding holds the value itself rather than an `Option`. With `Finalize::NonEmpty`,
+    /// it holds a `Result`.
+    fn provided(&self, mode: Finalize) -> TokenStream {
+        let binding = self.binding();
+        let root = self.receiver.crate_root();
+        match mode {
+            _ if !self.is_fallible() => quote!(true),
+            Finalize::FirstError if self.field.optional.is_present() => {
+                quote!(#binding.is_some())
+            }
+            Finalize::FirstError => quote!(true),
+            Finalize::NonEmpty if self.field.optional.is_present() => {
+                quote!(#binding.as_ref().is_ok_and(#root::export::Option::is_some))
+            }
+            Finalize::NonEmpty => quote!(#binding.is_ok()),
+            _ if self.field.optional.is_present() => {
+                quote!(#binding.as_ref().is_some_and(#root::export::Option::is_some))
+            }
+            _ => quote!(#binding.is_some()),
+        }
+    }
+
+    /// Statements that record an error for each of this field's constraints that does
+    /// not hold, or return it with `Finalize::FirstError`.
+    fn constraint_checks(&self, fields: &[ReceiverField], mode: Finalize) -> Vec<syn::Stmt> {
+        let root = self.receiver.crate_root();
+        self.field
+            .constraints()
+            .filter_map(|(name, constraint)| {
+                let other = fields
+                    .iter()
+                    .find(|other| other.field.ident.as_ref() == Some(&constraint.field))?;
+                let provided = self.provided(mode);
+                let other_provided = other.provided(mode);
+                let condition = if name == "requires" {
+                    quote!(#provided && !#other_provided)
+                } else {
+                    quote!(#provided && #other_provided)
+                };
+                let error = &constraint.error;
+                let push = match mode {
+                    Finalize::FirstError => {
+                        let err = self.first_error(quote!(#root::export::Into::into(#error)));
+                        quote!(return #root::export::Err(#err);)
+                    }
+                    // Fields are not labelled after their errors are taken, so the error
+                    // is labelled as it is recorded.
+                    Finalize::NonEmpty => self
+                        .receiver
+                        .push_error(self.first_error(quote!(#root::export::Into::into(#error)))),
+                    _ => self
+                        .receiver
+                        .push_error(quote!(#root::export::Into::into(#error))),
+                };
+                let cfg = self.cfg_attrs().chain(other.cfg_attrs());
+                Some(parse_quote! {
+                    #(#cfg)*
+                    if #condition {
+                        #push
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Convert one of this field's errors into the struct's error type.
+    fn err_value(&self, err: TokenStream) -> TokenStream {
+        let root = self.receiver.crate_root();
+        match &self.field.map_err {
+            Some(map_err) => quote!(#root::export::Into::into(#map_err(#err))),
+            // Spanned fields record `Spanned<E, S>`, which always needs converting.
+            None if self.field.error.is_some() || self.field.spanned.is_present() => {
+                quote!(#root::export::Into::into(#err))
+            }
+            None => err,
+        }
+    }
+
+    fn initializer(&self) -> syn::FieldValue {
+        let mut initializer = self.initializer_inner();
+        initializer.attrs.extend(self.cfg_attrs().cloned());
+        initializer
+    }
+
+    fn initializer_inner(&self) -> syn::FieldValue {
+        let member = self.member();
+
+        match &self.field.skip {
+            Some(Override::Explicit(expr)) => parse_quote! {
+                #member: #expr
+            },
+            Some(Override::Inherit) => {
+                let root = self.receiver.crate_root();
+                parse_quote! {
+                    #member: #root::export::Default::default()
+                }
+            }
+            None => {
+                let binding = self.binding();
+                parse_quote! {
+                    #member: #binding
+                }
+            }
+        }
+    }
+}
+
+pub mod export {
+    pub use std::clone::Clone;
+    pub use std::cmp::{Eq, Ord, PartialEq, PartialOrd};
+    pub use std::convert::{AsMut, AsRef, From, Into, TryFrom};
+    pub use std::default::Default;
+    pub use std::fmt;
+    pub use std::hash::Hash;
+    pub use std::iter::{DoubleEndedIterator, Extend, FromIterator, IntoIterator, Iterator};
+    pub use std::marker::{Copy, PhantomData};
+    pub use std::mem;
+    pub use std::ops::Fn;
+    pub use std::option::Option::{self, None, Some};
+    pub use std::result::Result::{self, Err, Ok};
+    pub use std::vec::Vec;
+
+    pub use crate::staged::TakeValues;
+    pub use crate::{AtLeastOne, ElementError, FieldError, FromStaging, Severity, Spanned, Staged};
+
+    #[cfg(feature = "serde")]
+    pub use crate::de::{DeserializeFields, deserialize_checker, deserialize_value};
+    #[cfg(feature = "serde")]
+    pub use serde;
 }
@@ -0,0 +1,76 @@
+use std::{
+    fmt,
+    ops::{Deref, DerefMut, Range},
+};
+
+/// A value along with the source span it was parsed from.
+///
+/// Fields marked `#[staging(spanned)]` are staged as `Spanned<Result<T, E>, S>`, and any
+/// error found during finalization is recorded as a `Spanned<E, S>` so it keeps pointing
+/// at the offending input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Spanned<T, S = Range<usize>> {
+    pub value: T,
+    pub span: S,
+}
+
+impl<T, S> Spanned<T, S> {
+    pub fn new(value: T, span: S) -> Self {
+        Self { value, span }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    pub fn as_ref(&self) -> Spanned<&T, &S> {
+        Spanned {
+            value: &self.value,
+            span: &self.span,
+        }
+    }
+
+    /// Transform the value, keeping the span.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Spanned<U, S> {
+        Spanned {
+            value: f(self.value),
+            span: self.span,
+        }
+    }
+}
+
+impl<T, E, S> Spanned<Result<T, E>, S> {
+    /// Move the span onto whichever side of the result is present.
+    pub fn transpose(self) -> Result<Spanned<T, S>, Spanned<E, S>> {
+        match self.value {
+            Ok(value) => Ok(Spanned::new(value, self.span)),
+            Err(err) => Err(Spanned::new(err, self.span)),
+        }
+    }
+}
+
+impl<T, S> Deref for Spanned<T, S> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, S> DerefMut for Spanned<T, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T: fmt::Display, S> fmt::Display for Spanned<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: std::error::Error, S: fmt::Debug> std::error::Error for Spanned<T, S> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.value.source()
+    }
+}
@@ -0,0 +1,79 @@
+/// A type with a derived checker, linking the two so generic code can stage any such
+/// type without naming its checker.
+///
+/// ```
+/// use staging_core::Staged;
+///
+/// fn load<T: Staged>(staging: T::Staging) -> Result<T, T::Error> {
+///     T::finalize(staging)
+/// }
+/// ```
+///
+/// The derive implements this alongside the `TryFrom` or `FromStaging` impl, so it is not
+/// implemented when `context` or `threshold` is set, when `no_try_from` is set without
+/// `from_staging`, with `generic_error`, for a `remote` type, or when `vis` makes the
+/// checker less visible than the type. A type with several profiles has several
+/// checkers, so only the profile marked `primary` implements it, and none does if no
+/// profile is marked.
+pub trait Staged: Sized {
+    /// The checker that builds this type.
+    type Staging;
+    /// The error returned when the checker cannot be finalized.
+    type Error;
+
+    /// Finalize `staging`, as its `TryFrom` or `FromStaging` impl does.
+    fn finalize(staging: Self::Staging) -> Result<Self, Self::Error>;
+}
+
+/// Build a value by finalizing the checker `S`.
+///
+/// This is the same conversion as `TryFrom<S>`, under a name that is specific to staging,
+/// so it does not overlap with other `TryFrom` impls for the type. The derive implements
+/// it when `from_staging` is set.
+pub trait FromStaging<S>: Sized {
+    /// The error returned when the checker cannot be finalized.
+    type Error;
+
+    fn from_staging(staging: S) -> Result<Self, Self::Error>;
+}
+
+/// Finalize a checker into `T`.
+///
+/// This is implemented for every checker whose target implements `FromStaging`, as
+/// `Into` is for `From`, so it should not be implemented directly.
+pub trait IntoFinal<T> {
+    /// The error returned when the checker cannot be finalized.
+    type Error;
+
+    fn into_final(self) -> Result<T, Self::Error>;
+}
+
+impl<S, T: FromStaging<S>> IntoFinal<T> for S {
+    type Error = T::Error;
+
+    fn into_final(self) -> Result<T, Self::Error> {
+        T::from_staging(self)
+    }
+}
+
+/// The values held by a checker with `try_finalize`, taken out of it only once none of
+/// its fields holds an error.
+///
+/// A parent checker takes the values of its `nested` fields along with its
+/// own, and puts them back if any field fails, so it is returned unchanged.
+#[doc(hidden)]
+pub trait TakeValues: Sized {
+    /// The value of each field, along with everything else the checker keeps.
+    type Values;
+    /// The type the checker finalizes into.
+    type Target;
+
+    /// Take the values out of the checker, or return it unchanged if it holds an error.
+    fn take_values(self) -> Result<Self::Values, Self>;
+
+    /// Put taken values back into a checker.
+    fn restore(values: Self::Values) -> Self;
+
+    /// Build the target from taken values.
+    fn build(values: Self::Values) -> Self::Target;
+}
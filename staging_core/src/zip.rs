@@ -0,0 +1,62 @@
+//! Functions that combine several results into one, keeping every error, as a checker
+//! does when it is finalized.
+
+macro_rules! zip {
+    ($(#[$attr:meta])* $name:ident($($value:ident: $ty:ident),+)) => {
+        $(#[$attr])*
+        pub fn $name<$($ty,)+ E>($($value: Result<$ty, E>),+) -> Result<($($ty,)+), Vec<E>> {
+            match ($($value,)+) {
+                ($(Ok($value),)+) => Ok(($($value,)+)),
+                ($($value,)+) => Err([$($value.err()),+].into_iter().flatten().collect()),
+            }
+        }
+    };
+}
+
+zip! {
+    /// Combine two results into a result of both values, or every error in order.
+    zip2(a: T1, b: T2)
+}
+zip! {
+    /// Combine three results into a result of their values, or every error in order.
+    zip3(a: T1, b: T2, c: T3)
+}
+zip! {
+    /// Combine four results into a result of their values, or every error in order.
+    zip4(a: T1, b: T2, c: T3, d: T4)
+}
+zip! {
+    /// Combine five results into a result of their values, or every error in order.
+    zip5(a: T1, b: T2, c: T3, d: T4, e: T5)
+}
+zip! {
+    /// Combine six results into a result of their values, or every error in order.
+    zip6(a: T1, b: T2, c: T3, d: T4, e: T5, f: T6)
+}
+
+/// Finalize every checker from `iter`, returning all of the targets, or the index and
+/// final error of each checker that failed, e.g. to report every bad row of a bulk import.
+///
+/// Every checker is finalized, even once one has failed.
+pub fn try_collect_all<S, T, E>(
+    iter: impl IntoIterator<Item = S>,
+) -> Result<Vec<T>, Vec<(usize, E)>>
+where
+    T: TryFrom<S, Error = E>,
+{
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    for (index, staging) in iter.into_iter().enumerate() {
+        match T::try_from(staging) {
+            Ok(value) if errors.is_empty() => values.push(value),
+            Ok(_) => {}
+            Err(err) => errors.push((index, err)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(errors)
+    }
+}
@@ -0,0 +1,542 @@
+//! Find the name the deriving crate uses for `staging`, so generated code still compiles
+//! when the dependency is renamed in `Cargo.toml`.
+//!
+//! This reads the manifest of the crate being compiled and looks for a dependency with
+//! `package = "staging"`, in any of the dependency tables. A dependency inherited with
+//! `workspace = true` is looked up in `[workspace.dependencies]` of the workspace's
+//! manifest. Only the parts of TOML that dependency declarations use are understood;
+//! other values are skipped.
+
+use std::{
+    env, fs,
+    path::{Path as FsPath, PathBuf},
+};
+
+use syn::Path;
+
+/// The package that provides the derive macro.
+const PACKAGE: &str = "staging";
+
+/// Path to the `staging` crate as the deriving crate names it, or `None` if the manifest
+/// cannot be read or does not rename it.
+pub fn detect() -> Option<Path> {
+    let dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR")?);
+    let manifest = fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+    let name = renamed_dependency(&manifest, || workspace_manifest(&dir))?;
+    syn::parse_str(&format!("::{}", name.replace('-', "_"))).ok()
+}
+
+/// The manifest of the workspace that the crate in `dir` belongs to: the closest one,
+/// starting from `dir` itself, that has a `[workspace]` table.
+fn workspace_manifest(dir: &FsPath) -> Option<String> {
+    dir.ancestors().find_map(|dir| {
+        let manifest = fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+        entries(&manifest)
+            .iter()
+            .any(|(key, _)| key[0] == "workspace")
+            .then_some(manifest)
+    })
+}
+
+/// The key of the dependency on `staging` in `manifest`, if it is declared under another
+/// name. `workspace` reads the workspace's manifest, and is only called if a dependency
+/// is inherited from it.
+fn renamed_dependency(
+    manifest: &str,
+    workspace: impl FnOnce() -> Option<String>,
+) -> Option<String> {
+    let declared = dependencies(&entries(manifest), false);
+    if let Some(dependency) = declared
+        .iter()
+        .find(|dependency| dependency.package.as_deref() == Some(PACKAGE))
+    {
+        return Some(dependency.name.clone());
+    }
+
+    if !declared.iter().any(|dependency| dependency.workspace) {
+        return None;
+    }
+
+    let inherited = dependencies(&entries(&workspace()?), true);
+    declared
+        .iter()
+        .filter(|dependency| dependency.workspace)
+        .find(|dependency| {
+            inherited.iter().any(|shared| {
+                shared.name == dependency.name && shared.package.as_deref() == Some(PACKAGE)
+            })
+        })
+        .map(|dependency| dependency.name.clone())
+}
+
+/// A dependency declared in a manifest.
+#[derive(Debug, PartialEq)]
+struct Dependency {
+    /// The table that declares it, such as `["dev-dependencies"]`, followed by its key.
+    path: Vec<String>,
+    name: String,
+    package: Option<String>,
+    workspace: bool,
+}
+
+/// The dependencies declared by `entries`: those of the crate, or with `workspace` set,
+/// those in `[workspace.dependencies]`.
+fn dependencies(entries: &[(Vec<String>, Value)], workspace: bool) -> Vec<Dependency> {
+    let mut dependencies: Vec<Dependency> = Vec::new();
+    for (key, value) in entries {
+        let table = match (key.as_slice(), workspace) {
+            ([first, second, ..], true) if first == "workspace" && second == "dependencies" => 1,
+            ([table, ..], false) if is_dependency_table(table) => 0,
+            ([target, _, table, ..], false) if target == "target" && is_dependency_table(table) => {
+                2
+            }
+            _ => continue,
+        };
+        let Some(name) = key.get(table + 1) else {
+            continue;
+        };
+
+        let path = &key[..table + 2];
+        let index = match dependencies
+            .iter()
+            .position(|dependency| dependency.path == path)
+        {
+            Some(index) => index,
+            None => {
+                dependencies.push(Dependency {
+                    path: path.to_vec(),
+                    name: name.clone(),
+                    package: None,
+                    workspace: false,
+                });
+                dependencies.len() - 1
+            }
+        };
+        let dependency = &mut dependencies[index];
+        match (&key[table + 2..], value) {
+            ([field], Value::String(package)) if field == "package" => {
+                dependency.package = Some(package.clone());
+            }
+            ([field], Value::Bool(inherited)) if field == "workspace" => {
+                dependency.workspace = *inherited;
+            }
+            _ => {}
+        }
+    }
+    dependencies
+}
+
+fn is_dependency_table(key: &str) -> bool {
+    matches!(
+        key,
+        "dependencies" | "dev-dependencies" | "build-dependencies"
+    )
+}
+
+/// A value in the manifest, as far as dependency declarations need it.
+#[derive(Debug, PartialEq)]
+enum Value {
+    String(String),
+    Bool(bool),
+    /// The header of a table, such as `[workspace]`, which may have no other entries.
+    Table,
+    /// An array, number or date.
+    Other,
+}
+
+/// Every table and value in `manifest` with its full key, including the table it is in.
+/// The entries of inline tables are listed with the key of the table before their own.
+fn entries(manifest: &str) -> Vec<(Vec<String>, Value)> {
+    let mut reader = Reader(manifest);
+    let mut entries = Vec::new();
+    let mut table = Vec::new();
+
+    loop {
+        reader.skip_space(true);
+        match reader.peek() {
+            None => break,
+            Some('[') => {
+                reader.bump();
+                // An array of tables such as `[[bin]]` has no dependencies, but is read
+                // the same way so that its keys are not mistaken for them.
+                reader.eat('[');
+                if let Some(key) = reader.key() {
+                    entries.push((key.clone(), Value::Table));
+                    table = key;
+                }
+            }
+            Some(_) => {
+                if let Some(key) = reader.key() {
+                    reader.skip_space(false);
+                    if reader.eat('=') {
+                        let key = table.iter().cloned().chain(key).collect();
+                        reader.value(key, &mut entries);
+                    }
+                }
+            }
+        }
+        reader.skip_line();
+    }
+
+    entries
+}
+
+/// Reads TOML from the start of the text it holds.
+struct Reader<'a>(&'a str);
+
+impl Reader<'_> {
+    fn peek(&self) -> Option<char> {
+        self.0.chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.0 = &self.0[c.len_utf8()..];
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        match self.0.strip_prefix(c) {
+            Some(rest) => {
+                self.0 = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Skip whitespace and comments, and line breaks too if `newlines` is set.
+    fn skip_space(&mut self, newlines: bool) {
+        loop {
+            match self.peek() {
+                Some('\n') if !newlines => break,
+                Some(c) if c.is_whitespace() => {
+                    self.bump();
+                }
+                Some('#') => {
+                    let end = self.0.find('\n').unwrap_or(self.0.len());
+                    self.0 = &self.0[end..];
+                }
+                _ => break,
+            }
+        }
+    }
+
+    /// Skip the rest of the line, such as the `]` after a table header.
+    fn skip_line(&mut self) {
+        let end = self.0.find('\n').map_or(self.0.len(), |end| end + 1);
+        self.0 = &self.0[end..];
+    }
+
+    /// A dotted key such as `target.'cfg(unix)'.dependencies`, split into its parts.
+    fn key(&mut self) -> Option<Vec<String>> {
+        let mut keys = Vec::new();
+        loop {
+            self.skip_space(false);
+            keys.push(self.simple_key()?);
+            self.skip_space(false);
+            if !self.eat('.') {
+                return Some(keys);
+            }
+        }
+    }
+
+    fn simple_key(&mut self) -> Option<String> {
+        if let Some('"' | '\'') = self.peek() {
+            return self.string();
+        }
+
+        let end = self
+            .0
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+            .unwrap_or(self.0.len());
+        let (key, rest) = self.0.split_at(end);
+        self.0 = rest;
+        (!key.is_empty()).then(|| key.to_string())
+    }
+
+    /// A basic or literal string, on one line or several.
+    fn string(&mut self) -> Option<String> {
+        for delimiter in ["\"\"\"", "'''"] {
+            if let Some(rest) = self.0.strip_prefix(delimiter) {
+                let end = rest.find(delimiter)?;
+                self.0 = &rest[end + delimiter.len()..];
+                return Some(rest[..end].to_string());
+            }
+        }
+
+        let quote = self.bump()?;
+        let mut value = String::new();
+        loop {
+            match self.bump()? {
+                '\n' => return None,
+                c if c == quote => return Some(value),
+                '\\' if quote == '"' => value.push(self.bump()?),
+                c => value.push(c),
+            }
+        }
+    }
+
+    /// Read a value for `key`, adding it to `entries`.
+    fn value(&mut self, key: Vec<String>, entries: &mut Vec<(Vec<String>, Value)>) {
+        self.skip_space(false);
+        match self.peek() {
+            Some('"' | '\'') => {
+                if let Some(value) = self.string() {
+                    entries.push((key, Value::String(value)));
+                }
+            }
+            Some('{') => {
+                self.bump();
+                // Inline tables may span several lines, as TOML 1.1 allows.
+                loop {
+                    self.skip_space(true);
+                    if self.eat('}') {
+                        break;
+                    }
+                    let Some(inner) = self.key() else {
+                        break;
+                    };
+                    self.skip_space(false);
+                    if !self.eat('=') {
+                        break;
+                    }
+                    self.value(key.iter().cloned().chain(inner).collect(), entries);
+                    self.skip_space(true);
+                    self.eat(',');
+                }
+            }
+            Some('[') => {
+                self.skip_array();
+                entries.push((key, Value::Other));
+            }
+            _ => {
+                let end = self
+                    .0
+                    .find(|c: char| c.is_whitespace() || matches!(c, ',' | '}' | ']' | '#'))
+                    .unwrap_or(self.0.len());
+                let (value, rest) = self.0.split_at(end);
+                self.0 = rest;
+                let value = match value {
+                    "true" => Value::Bool(true),
+                    "false" => Value::Bool(false),
+                    _ => Value::Other,
+                };
+                entries.push((key, value));
+            }
+        }
+    }
+
+    /// Skip an array such as `features = ["a", "b"]`, which may span several lines.
+    fn skip_array(&mut self) {
+        let mut depth = 0;
+        loop {
+            self.skip_space(true);
+            match self.peek() {
+                None => return,
+                Some('"' | '\'') => {
+                    if self.string().is_none() {
+                        return;
+                    }
+                }
+                Some(c) => {
+                    self.bump();
+                    match c {
+                        '[' | '{' => depth += 1,
+                        ']' | '}' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                return;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::renamed_dependency;
+
+    fn renamed(manifest: &str) -> Option<String> {
+        renamed_dependency(manifest, || panic!("the workspace manifest is not needed"))
+    }
+
+    #[test]
+    fn plain_dependency() {
+        let manifest = r#"
+            [package]
+            name = "app"
+
+            [dependencies]
+            staging = "0.2"
+            serde = { version = "1", features = ["derive"] }
+        "#;
+        assert_eq!(renamed(manifest), None);
+    }
+
+    #[test]
+    fn package_rename() {
+        let manifest = r#"
+            [dependencies]
+            serde = "1"
+            checks = { version = "0.2", package = "staging" }
+        "#;
+        assert_eq!(renamed(manifest).as_deref(), Some("checks"));
+    }
+
+    #[test]
+    fn dotted_package_rename() {
+        let manifest = r#"
+            [dependencies]
+            checks.version = "0.2"
+            checks.package = "staging"
+        "#;
+        assert_eq!(renamed(manifest).as_deref(), Some("checks"));
+    }
+
+    #[test]
+    fn table_rename() {
+        let manifest = r#"
+            [dependencies.checks]
+            version = "0.2"
+            package = "staging"
+            features = ["serde"]
+
+            [dependencies.other]
+            package = "other"
+        "#;
+        assert_eq!(renamed(manifest).as_deref(), Some("checks"));
+    }
+
+    #[test]
+    fn dev_and_target_dependencies() {
+        let manifest = r#"
+            [dev-dependencies]
+            checks = { package = "staging", version = "0.2" }
+        "#;
+        assert_eq!(renamed(manifest).as_deref(), Some("checks"));
+
+        let manifest = r#"
+            [target.'cfg(unix)'.build-dependencies]
+            unix-checks = { package = "staging" }
+        "#;
+        assert_eq!(renamed(manifest).as_deref(), Some("unix-checks"));
+    }
+
+    #[test]
+    fn comments() {
+        let manifest = r#"
+            [dependencies] # checks = { package = "staging" }
+            # checks = { package = "staging" }
+            description = "uses # in a string" # package = "staging"
+            checks = { package = "staging" } # the # is not part of the key
+        "#;
+        assert_eq!(renamed(manifest).as_deref(), Some("checks"));
+
+        let manifest = r#"
+            [dependencies]
+            # checks = { package = "staging" }
+        "#;
+        assert_eq!(renamed(manifest), None);
+    }
+
+    #[test]
+    fn quoted_keys() {
+        let manifest = r#"
+            [dependencies]
+            "checks" = { "package" = 'staging' }
+        "#;
+        assert_eq!(renamed(manifest).as_deref(), Some("checks"));
+
+        let manifest = r#"
+            [dependencies.'checks']
+            "package" = "staging"
+        "#;
+        assert_eq!(renamed(manifest).as_deref(), Some("checks"));
+    }
+
+    #[test]
+    fn multi_line_inline_table() {
+        let manifest = r#"
+            [dependencies]
+            checks = {
+                version = ">=0.2, <0.3",
+                features = [
+                    "serde",
+                    "async",
+                ],
+                package = "staging",
+            }
+        "#;
+        assert_eq!(renamed(manifest).as_deref(), Some("checks"));
+    }
+
+    #[test]
+    fn multi_line_strings() {
+        let manifest = r#"
+            [package]
+            description = """
+            [dependencies]
+            checks = { package = "staging" }
+            """
+
+            [dependencies]
+            staging = "0.2"
+        "#;
+        assert_eq!(renamed(manifest), None);
+    }
+
+    #[test]
+    fn workspace_inheritance() {
+        let workspace = r#"
+            [workspace]
+            members = ["app"]
+
+            [workspace.dependencies]
+            serde = "1"
+            checks = { version = "0.2", package = "staging" }
+        "#;
+
+        for manifest in [
+            "[dependencies]\nchecks = { workspace = true }",
+            "[dependencies]\nchecks.workspace = true",
+            "[dependencies.checks]\nworkspace = true\nfeatures = [\"serde\"]",
+        ] {
+            let found = renamed_dependency(manifest, || Some(workspace.to_string()));
+            assert_eq!(found.as_deref(), Some("checks"), "{}", manifest);
+        }
+
+        let manifest = "[dependencies]\nserde = { workspace = true }";
+        let found = renamed_dependency(manifest, || Some(workspace.to_string()));
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn workspace_table_inheritance() {
+        let workspace = r#"
+            [workspace.dependencies.checks]
+            version = "0.2"
+            package = "staging"
+        "#;
+        let manifest = "[dev-dependencies]\nchecks = { workspace = true }";
+        let found = renamed_dependency(manifest, || Some(workspace.to_string()));
+        assert_eq!(found.as_deref(), Some("checks"));
+    }
+
+    #[test]
+    fn no_dependency() {
+        assert_eq!(renamed(""), None);
+        assert_eq!(renamed("[package]\nname = \"app\"\n"), None);
+
+        // The crate's own dependencies are not those of the workspace.
+        let manifest = r#"
+            [workspace.dependencies]
+            checks = { package = "staging" }
+        "#;
+        assert_eq!(renamed(manifest), None);
+    }
+}
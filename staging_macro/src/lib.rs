@@ -1,14 +1,29 @@
 use proc_macro::TokenStream;
-use staging_core::derive_staging_with_crate_root;
-use syn::parse_quote;
+use staging_core::{StagingOptions, derive_staging_with_crate_root, staged_with_options};
+use syn::{Path, parse_quote};
+
+mod crate_root;
 
 #[proc_macro_derive(Staging, attributes(staging))]
 pub fn derive(input: TokenStream) -> TokenStream {
-    derive_staging_with_crate_root(
-        input.into(),
-        // If this is being invoked, the caller is using the `staging` crate and may
-        // not have `staging_core` crate imported, so default to `staging` as crate root.
-        Some(parse_quote!(::staging)),
-    )
-    .into()
+    derive_staging_with_crate_root(input.into(), Some(default_crate_root())).into()
+}
+
+/// Generate the checker like `#[derive(Staging)]`, taking the struct options as its own
+/// arguments, and rewrite the annotated type: `staging` attributes are removed from it,
+/// and with `serde_try_from` it deserializes through its checker.
+#[proc_macro_attribute]
+pub fn staged(args: TokenStream, item: TokenStream) -> TokenStream {
+    let options = StagingOptions {
+        crate_root: Some(default_crate_root()),
+        ..StagingOptions::default()
+    };
+    staged_with_options(args.into(), item.into(), &options).into()
+}
+
+/// If this is being invoked, the caller is using the `staging` crate and may not have
+/// `staging_core` crate imported, so default to `staging` as crate root, under whatever
+/// name the caller's `Cargo.toml` gives it.
+fn default_crate_root() -> Path {
+    crate_root::detect().unwrap_or_else(|| parse_quote!(::staging))
 }